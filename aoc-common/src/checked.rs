@@ -0,0 +1,114 @@
+/// Integer type for answers that may outgrow it on big inputs, see [`checked_add`].
+pub trait CheckedInt: Copy + Default {
+    const NAME: &'static str;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_int {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedInt for $t {
+                const NAME: &'static str = stringify!($t);
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_int!(i32, i64, i128, u32, u64, u128, usize);
+
+#[track_caller]
+fn overflow<T: CheckedInt>(what: &str) -> T {
+    panic!("Overflow computing {what}, which exceeds the range of {}", T::NAME)
+}
+
+/// `a + b`, panicking with an error naming `what` overflowed instead of wrapping around.
+/// The answers stay correct or fail loudly, also in release builds.
+#[track_caller]
+pub fn checked_add<T: CheckedInt>(a: T, b: T, what: &str) -> T {
+    match a.checked_add(b) {
+        Some(value) => value,
+        None => overflow(what),
+    }
+}
+
+/// `a - b`, see [`checked_add`].
+#[track_caller]
+pub fn checked_sub<T: CheckedInt>(a: T, b: T, what: &str) -> T {
+    match a.checked_sub(b) {
+        Some(value) => value,
+        None => overflow(what),
+    }
+}
+
+/// `a * b`, see [`checked_add`].
+#[track_caller]
+pub fn checked_mul<T: CheckedInt>(a: T, b: T, what: &str) -> T {
+    match a.checked_mul(b) {
+        Some(value) => value,
+        None => overflow(what),
+    }
+}
+
+/// Sum of `values`, see [`checked_add`].
+#[track_caller]
+pub fn checked_sum<T: CheckedInt, I: IntoIterator<Item = T>>(values: I, what: &str) -> T {
+    // Loops instead of folds, as closures would hide the caller from the panic location
+    let mut sum = T::default();
+    for value in values {
+        sum = checked_add(sum, value, what);
+    }
+    sum
+}
+
+/// Product of `values`, see [`checked_add`].
+#[track_caller]
+pub fn checked_product<T: CheckedInt, I: IntoIterator<Item = T>>(values: I, what: &str) -> T {
+    let mut product = T::ONE;
+    for value in values {
+        product = checked_mul(product, value, what);
+    }
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(checked_add(u64::MAX - 1, 1, "x"), u64::MAX);
+        assert_eq!(checked_sub(i64::MIN + 1, 1, "x"), i64::MIN);
+        assert_eq!(checked_sum([1u64, 2, 3], "x"), 6);
+        assert_eq!(checked_product(Vec::<u64>::new(), "x"), 1);
+        assert_eq!(checked_product([3u128, 5], "x"), 15);
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow computing the total, which exceeds the range of u32")]
+    fn overflowing_sum() {
+        checked_sum([u32::MAX, 1], "the total");
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow computing the ratio, which exceeds the range of i64")]
+    fn overflowing_product() {
+        checked_mul(i64::MAX / 2, 3, "the ratio");
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Default location of the config, relative to the current directory.
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+/// Variable to read the config from another file than [`CONFIG_PATH`].
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+fn day_number(key: &str) -> Option<u32> {
+    key.strip_prefix("day")?.parse().ok()
+}
+
+/// The values a parameter takes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParamKind {
+    Bool,
+    /// An integer from `min` to `max`
+    Int { min: i128, max: i128 },
+    /// A number from `min` to `max`
+    Float { min: f64, max: f64 },
+}
+
+/// A tunable parameter that a day reads with [`param`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub kind: ParamKind,
+}
+
+impl Param {
+    pub const fn bool(name: &'static str) -> Self {
+        Self { name, kind: ParamKind::Bool }
+    }
+
+    pub const fn int(name: &'static str, min: i128, max: i128) -> Self {
+        Self { name, kind: ParamKind::Int { min, max } }
+    }
+
+    pub const fn float(name: &'static str, min: f64, max: f64) -> Self {
+        Self { name, kind: ParamKind::Float { min, max } }
+    }
+
+    fn check(&self, value: &str) -> Result<(), String> {
+        let (valid, expected) = match self.kind {
+            ParamKind::Bool => (value.parse::<bool>().is_ok(), String::from("true or false")),
+            ParamKind::Int { min, max } => (
+                value.parse::<i128>().is_ok_and(|value| (min..=max).contains(&value)),
+                format!("an integer from {min} to {max}"),
+            ),
+            ParamKind::Float { min, max } => (
+                value.parse::<f64>().is_ok_and(|value| (min..=max).contains(&value)),
+                format!("a number from {min} to {max}"),
+            ),
+        };
+        if valid { Ok(()) } else { Err(format!("expected {expected}")) }
+    }
+}
+
+/// Tunable puzzle parameters of the days, like `red = 12` in the table `[day02]`.
+/// The values are kept as text until a day reads them with [`param`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    days: BTreeMap<u32, BTreeMap<String, String>>,
+}
+
+impl Config {
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        let table: toml::Table = toml::from_str(text).map_err(|e| e.to_string())?;
+        let mut config = Self::default();
+        for (key, params) in table {
+            let day = day_number(&key).ok_or_else(|| format!("Expected tables like [day02], found \"{key}\""))?;
+            let params = params.as_table().ok_or_else(|| format!("Expected [{key}] to be a table"))?;
+            for (name, value) in params {
+                let value = match value {
+                    toml::Value::String(value) => value.clone(),
+                    toml::Value::Integer(_) | toml::Value::Float(_) | toml::Value::Boolean(_) => value.to_string(),
+                    _ => return Err(format!("Expected a number, boolean or string for {key}.{name}")),
+                };
+                config.days.entry(day).or_default().insert(name.clone(), value);
+            }
+        }
+        Ok(config)
+    }
+
+    /// The config at `$AOC_CONFIG` or else [`CONFIG_PATH`], which is empty if the default file does not exist.
+    pub fn load() -> Result<Self, String> {
+        let (path, required) = match env::var_os(CONFIG_VAR) {
+            Some(path) => (PathBuf::from(path), true),
+            None => (PathBuf::from(CONFIG_PATH), false),
+        };
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::from_toml(&text).map_err(|e| format!("Invalid config {}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound && !required => Ok(Self::default()),
+            Err(e) => Err(format!("Cannot read config {}: {e}", path.display())),
+        }
+    }
+
+    /// Sets a parameter from an override like `day02.red=20`.
+    pub fn set(&mut self, assignment: &str) -> Result<(), String> {
+        let error = || format!("Expected an override like \"day02.red=20\", got \"{assignment}\"");
+        let (key, value) = assignment.split_once('=').ok_or_else(error)?;
+        let (day, name) = key.trim().split_once('.').ok_or_else(error)?;
+        let day = day_number(day).ok_or_else(error)?;
+        if name.is_empty() {
+            return Err(error());
+        }
+        self.days.entry(day).or_default().insert(name.to_string(), value.trim().to_string());
+        Ok(())
+    }
+
+    pub fn get(&self, day: u32, name: &str) -> Option<&str> {
+        self.days.get(&day).and_then(|params| params.get(name)).map(String::as_str)
+    }
+
+    /// The days with configured parameters.
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.days.keys().copied()
+    }
+
+    /// Checks the configured parameters of `day` against the `params` it reads:
+    /// unknown names and invalid values are errors.
+    pub fn validate(&self, day: u32, params: &[Param]) -> Result<(), String> {
+        for (name, value) in self.days.get(&day).into_iter().flatten() {
+            let Some(param) = params.iter().find(|param| param.name == name) else {
+                let names: Vec<&str> = params.iter().map(|param| param.name).collect();
+                let expected = if names.is_empty() { String::from("it has none") } else { format!("expected one of {}", names.join(", ")) };
+                return Err(format!("Unknown parameter day{day:02}.{name} in the config, {expected}"));
+            };
+            param.check(value).map_err(|e| format!("Invalid value \"{value}\" for day{day:02}.{name} in the config, {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Loads the config (see [`Config::load`]), applies the `overrides`, checks it with `validate`
+/// (e.g. with [`Config::validate`]) and makes it the one [`param`] reads from.
+/// Only the first config set this way is used.
+pub fn init_config<V: FnOnce(&Config) -> Result<(), String>>(overrides: &[String], validate: V) -> Result<(), String> {
+    let mut config = Config::load()?;
+    for assignment in overrides {
+        config.set(assignment)?;
+    }
+    validate(&config)?;
+    let _ = CONFIG.set(config);
+    Ok(())
+}
+
+/// The parameter `name` of `day` from the config set by [`init_config`], or `default`
+/// if it is not configured. Panics if the configured value is no valid `T`, which
+/// [`Config::validate`] rules out for the parameters the day lists.
+#[track_caller]
+pub fn param<T: FromStr>(day: u32, name: &str, default: T) -> T {
+    let Some(value) = CONFIG.get().and_then(|config| config.get(day, name)) else {
+        return default;
+    };
+    match value.parse() {
+        Ok(value) => value,
+        Err(_) => panic!("Invalid value \"{value}\" for day{day:02}.{name} in the config"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_toml() {
+        let config = Config::from_toml("[day02]\nred = 20\n\n[day11]\nfactor = 1.5\nname = \"x\"\nflag = false\n").unwrap();
+        assert_eq!(config.get(2, "red"), Some("20"));
+        assert_eq!(config.get(11, "factor"), Some("1.5"));
+        assert_eq!(config.get(11, "name"), Some("x"));
+        assert_eq!(config.get(11, "flag"), Some("false"));
+        assert_eq!(config.get(2, "blue"), None);
+        assert!(Config::from_toml("red = 20\n").is_err());
+        assert!(Config::from_toml("[days]\nred = 20\n").is_err());
+        assert!(Config::from_toml("[day02]\nred = [1, 2]\n").is_err());
+    }
+
+    #[test]
+    fn overrides() {
+        let mut config = Config::from_toml("[day02]\nred = 20\n").unwrap();
+        config.set("day02.red=7").unwrap();
+        config.set("day16.max_steps = 100").unwrap();
+        assert_eq!(config.get(2, "red"), Some("7"));
+        assert_eq!(config.get(16, "max_steps"), Some("100"));
+        assert!(config.set("day02.red").is_err());
+        assert!(config.set("red=7").is_err());
+        assert!(config.set("day02.=7").is_err());
+    }
+
+    #[test]
+    fn validate() {
+        let params = [Param::int("red", 0, 100), Param::bool("flag"), Param::float("share", 0.0, 1.0)];
+        let config = |text: &str| Config::from_toml(text).unwrap();
+        assert_eq!(config("[day02]\nred = 20\nflag = true\nshare = 0.5\n").validate(2, &params), Ok(()));
+        assert_eq!(config("[day03]\nrde = 20\n").validate(2, &params), Ok(()));
+        assert_eq!(
+            config("[day02]\nrde = 20\n").validate(2, &params),
+            Err(String::from("Unknown parameter day02.rde in the config, expected one of red, flag, share"))
+        );
+        assert_eq!(
+            config("[day02]\nred = \"abc\"\n").validate(2, &params),
+            Err(String::from("Invalid value \"abc\" for day02.red in the config, expected an integer from 0 to 100"))
+        );
+        assert!(config("[day02]\nred = -1\n").validate(2, &params).is_err());
+        assert!(config("[day02]\nflag = 1\n").validate(2, &params).is_err());
+        assert!(config("[day02]\nshare = 1.5\n").validate(2, &params).is_err());
+        assert!(config("[day02]\nred = 1\n").validate(2, &[]).unwrap_err().ends_with("it has none"));
+        assert_eq!(config("[day02]\nred = 1\n[day16]\nx = 1\n").days().collect::<Vec<_>>(), [2, 16]);
+    }
+
+    #[test]
+    fn defaults_without_config() {
+        assert_eq!(param(99, "anything", 42), 42);
+    }
+}
@@ -0,0 +1,158 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Rng;
+
+/// Variable to change the directory that [`assert_matches_oracle`] writes reproducers to.
+pub const REPRODUCER_DIR_VAR: &str = "AOC_REPRODUCER_DIR";
+
+/// Directory for the inputs on which a solver and its oracle disagree,
+/// `$AOC_REPRODUCER_DIR` if set, else `target/reproducers` in the workspace.
+pub fn reproducer_dir() -> PathBuf {
+    match env::var_os(REPRODUCER_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("target").join("reproducers"),
+    }
+}
+
+/// Smaller variants of `input`: without one of its lines, without the n-th whitespace
+/// separated token of every line, with one number halved or without one character.
+fn shrink_candidates(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let join = |lines: Vec<String>| lines.join("\n") + "\n";
+    let mut candidates = Vec::new();
+
+    for i in 0..lines.len() {
+        let mut shorter: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+        shorter.remove(i);
+        candidates.push(join(shorter));
+    }
+
+    let n_tokens = lines.iter().map(|line| line.split_whitespace().count()).max().unwrap_or(0);
+    for n in 0..n_tokens {
+        candidates.push(join(lines.iter()
+            .map(|line| line.split_whitespace().enumerate().filter(|(i, _)| *i != n).map(|(_, token)| token).collect::<Vec<_>>().join(" "))
+            .collect()));
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        for (j, token) in tokens.iter().enumerate() {
+            if let Ok(number) = token.parse::<u64>() {
+                if number > 0 {
+                    let mut halved: Vec<String> = tokens.iter().map(|token| token.to_string()).collect();
+                    halved[j] = (number / 2).to_string();
+                    let mut shorter: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+                    shorter[i] = halved.join(" ");
+                    candidates.push(join(shorter));
+                }
+            }
+        }
+    }
+
+    // There are many of these, so only try them once the input is small
+    if input.len() <= 500 {
+        for (i, char) in input.char_indices().filter(|(_, char)| *char != '\n') {
+            candidates.push(format!("{}{}", &input[..i], &input[i + char.len_utf8()..]));
+        }
+    }
+    candidates.retain(|candidate| candidate != input);
+    candidates
+}
+
+/// Shrinks `input` while `check` still finds a mismatch.
+fn shrink<C: Fn(&str) -> Option<String>>(input: &str, check: &C) -> String {
+    let mut input = input.to_string();
+    while let Some(smaller) = shrink_candidates(&input).into_iter().find(|candidate| check(candidate).is_some()) {
+        input = smaller;
+    }
+    input
+}
+
+/// Differential test: runs `check` on `runs` inputs from `generate`, with seeds counting up
+/// from 0 and sizes cycling from 1 to `max_size`.
+///
+/// `check` compares the solver with a slow but obviously correct reference oracle, returning
+/// a description of the mismatch if they disagree, and `None` if they agree or the input is
+/// invalid. The first input with a mismatch is shrunk, written to `<name>.txt` in
+/// [`reproducer_dir`], and the function panics with the mismatch found for it.
+pub fn assert_matches_oracle<G, C>(name: &str, runs: u64, max_size: usize, generate: G, check: C)
+    where
+        G: Fn(&mut Rng, usize) -> String,
+        C: Fn(&str) -> Option<String>,
+{
+    assert_matches_oracle_in(&reproducer_dir(), name, runs, max_size, generate, check);
+}
+
+/// [`assert_matches_oracle`], writing the reproducer to `dir`.
+fn assert_matches_oracle_in<G, C>(dir: &Path, name: &str, runs: u64, max_size: usize, generate: G, check: C)
+    where
+        G: Fn(&mut Rng, usize) -> String,
+        C: Fn(&str) -> Option<String>,
+{
+    for seed in 0..runs {
+        let size = 1 + seed as usize % max_size;
+        let input = generate(&mut Rng::new(seed), size);
+        if check(&input).is_none() {
+            continue;
+        }
+        let shrunk = shrink(&input, &check);
+        let mismatch = check(&shrunk).unwrap();
+        let path = dir.join(format!("{name}.txt"));
+        let written = fs::create_dir_all(dir).and_then(|_| fs::write(&path, &shrunk));
+        let location = match written {
+            Ok(()) => format!("written to {}", path.display()),
+            Err(e) => format!("could not be written to {}: {e}", path.display()),
+        };
+        panic!(
+            "{name}: solver and oracle disagree on the input of seed {seed} with size {size}\n\
+            {mismatch}\nShrunk input {location}:\n{shrunk}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(rng: &mut Rng, size: usize) -> String {
+        (0..size).map(|_| format!("{} {}\n", rng.range(0..=100), rng.range(0..=100))).collect()
+    }
+
+    // Pretends that the solver gets numbers from 60 on wrong
+    fn check(input: &str) -> Option<String> {
+        let large = input.split_whitespace().filter_map(|token| token.parse::<u32>().ok()).find(|n| *n >= 60)?;
+        Some(format!("wrong for {large}"))
+    }
+
+    #[test]
+    fn candidates() {
+        let candidates = shrink_candidates("1 2\n3 4\n");
+        assert!(candidates.contains(&String::from("3 4\n")));
+        assert!(candidates.contains(&String::from("2\n4\n")));
+        assert!(candidates.contains(&String::from("1 1\n3 4\n")));
+        assert!(candidates.contains(&String::from("1 2\n3 \n")));
+        assert!(!candidates.contains(&String::from("1 2\n3 4\n")));
+    }
+
+    #[test]
+    fn shrinks_to_single_number() {
+        let shrunk = shrink("3 17\n80 4\n12 99\n", &check);
+        assert_eq!(shrunk.split_whitespace().count(), 1);
+        assert!(check(&shrunk).is_some());
+    }
+
+    #[test]
+    fn writes_reproducer() {
+        let dir = env::temp_dir().join(format!("aoc-reproducers-{}", std::process::id()));
+        let result = std::panic::catch_unwind(|| assert_matches_oracle_in(&dir, "numbers", 100, 5, numbers, check));
+
+        let message = result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("numbers: solver and oracle disagree on the input of seed 0"));
+        let reproducer = fs::read_to_string(dir.join("numbers.txt")).unwrap();
+        assert_eq!(reproducer.split_whitespace().count(), 1);
+        fs::remove_dir_all(dir).unwrap();
+        assert_matches_oracle("numbers", 100, 5, |_, _| String::from("1 2\n"), check);
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt::Write;
+
+use serde::Serialize;
+
+/// How a day treated one line of the input on the way to its answers: a one-line summary
+/// with details below it. Days with the whole input on one line explain it item by item,
+/// all on line 1.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Explanation {
+    /// Number of the input line, starting at 1
+    pub line: usize,
+    pub summary: String,
+    pub details: Vec<String>,
+}
+
+impl Explanation {
+    pub fn new(line: usize, summary: impl Into<String>) -> Self {
+        Self { line, summary: summary.into(), details: Vec::new() }
+    }
+
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.details.push(detail.into());
+        self
+    }
+}
+
+/// Renders the explanations as text, with the details indented below their summary.
+pub fn render_explanations(explanations: &[Explanation]) -> String {
+    let mut res = String::new();
+    for explanation in explanations {
+        writeln!(res, "Line {}: {}", explanation.line, explanation.summary).unwrap();
+        for detail in &explanation.details {
+            writeln!(res, "    {detail}").unwrap();
+        }
+    }
+    res
+}
+
+/// Renders the explanations as a JSON array of objects with the fields `line`, `summary` and `details`.
+pub fn render_explanations_json(explanations: &[Explanation]) -> String {
+    serde_json::to_string_pretty(explanations).unwrap() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explanations() -> Vec<Explanation> {
+        vec![
+            Explanation::new(1, "Game 1 is possible").detail("fewest cubes: 4 red"),
+            Explanation::new(3, "Game 3 is \"impossible\""),
+        ]
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(render_explanations(&explanations()), "Line 1: Game 1 is possible\n    fewest cubes: 4 red\nLine 3: Game 3 is \"impossible\"\n");
+    }
+
+    #[test]
+    fn json() {
+        let json: serde_json::Value = serde_json::from_str(&render_explanations_json(&explanations())).unwrap();
+        assert_eq!(json, serde_json::json!([
+            {"line": 1, "summary": "Game 1 is possible", "details": ["fewest cubes: 4 red"]},
+            {"line": 3, "summary": "Game 3 is \"impossible\"", "details": []},
+        ]));
+        assert_eq!(render_explanations_json(&[]), "[]\n");
+    }
+}
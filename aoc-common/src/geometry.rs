@@ -0,0 +1,325 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// Integer type usable as coordinate of a [`Point`] or [`Vector`].
+pub trait Coord: Copy + Debug + Default + Eq + Ord + Hash + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Distance between `self` and `other`, which does not overflow for unsigned types.
+    fn abs_diff(self, other: Self) -> Self {
+        self.max(other) - self.min(other)
+    }
+}
+
+/// Coordinate type which can also hold negative values, needed for vectors along a [`Direction`].
+pub trait SignedCoord: Coord + Neg<Output = Self> {}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl SignedCoord for i8 {}
+impl SignedCoord for i16 {}
+impl SignedCoord for i32 {}
+impl SignedCoord for i64 {}
+impl SignedCoord for i128 {}
+impl SignedCoord for isize {}
+
+/// A position. `y` grows downwards, so a point is the cell in column `x` and row `y` of a [`Grid`](crate::Grid).
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A displacement between two points.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Vector<T> {
+    pub dx: T,
+    pub dy: T,
+}
+
+impl<T: Coord> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The neighbouring point in `direction`, `None` if a coordinate would overflow.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+        let shift = |value: T, delta: i8| match delta {
+            -1 => value.checked_sub(T::ONE),
+            1 => value.checked_add(T::ONE),
+            _ => Some(value),
+        };
+        Some(Self { x: shift(self.x, dx)?, y: shift(self.y, dy)? })
+    }
+
+    /// `self + vector`, `None` if a coordinate would overflow.
+    pub fn checked_add(self, vector: Vector<T>) -> Option<Self> {
+        Some(Self { x: self.x.checked_add(vector.dx)?, y: self.y.checked_add(vector.dy)? })
+    }
+
+    /// `self - vector`, `None` if a coordinate would overflow.
+    pub fn checked_sub(self, vector: Vector<T>) -> Option<Self> {
+        Some(Self { x: self.x.checked_sub(vector.dx)?, y: self.y.checked_sub(vector.dy)? })
+    }
+
+    /// Converts to another coordinate type, `None` if a coordinate does not fit into it.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point { x: U::try_from(self.x).ok()?, y: U::try_from(self.y).ok()? })
+    }
+}
+
+impl<T: Coord> Add<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, vector: Vector<T>) -> Self::Output {
+        Point { x: self.x + vector.dx, y: self.y + vector.dy }
+    }
+}
+
+impl<T: Coord> AddAssign<Vector<T>> for Point<T> {
+    fn add_assign(&mut self, vector: Vector<T>) {
+        *self = *self + vector;
+    }
+}
+
+impl<T: Coord> Sub<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, vector: Vector<T>) -> Self::Output {
+        Point { x: self.x - vector.dx, y: self.y - vector.dy }
+    }
+}
+
+impl<T: Coord> Sub for Point<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Vector { dx: self.x - other.x, dy: self.y - other.y }
+    }
+}
+
+impl<T: Coord> Add for Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, other: Self) -> Self::Output {
+        Vector { dx: self.dx + other.dx, dy: self.dy + other.dy }
+    }
+}
+
+impl<T: Coord> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Vector { dx: self.dx * factor, dy: self.dy * factor }
+    }
+}
+
+impl<T: SignedCoord> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        Vector { dx: -self.dx, dy: -self.dy }
+    }
+}
+
+/// A compass direction. North is up, i.e. towards smaller `y`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// The orthogonal directions, clockwise starting with north.
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    /// All directions, clockwise starting with north.
+    pub const ALL: [Direction; 8] = [
+        Direction::N, Direction::NE, Direction::E, Direction::SE,
+        Direction::S, Direction::SW, Direction::W, Direction::NW,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|direction| *direction == self).unwrap()
+    }
+
+    /// Turns clockwise by `eighths` eighths of a full turn.
+    fn turn(self, eighths: usize) -> Self {
+        Self::ALL[(self.index() + eighths) % 8]
+    }
+
+    /// Turns by 90° counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.turn(6)
+    }
+
+    /// Turns by 90° clockwise.
+    pub fn turn_right(self) -> Self {
+        self.turn(2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(4)
+    }
+
+    /// Direction after being reflected at a mirror shaped like `/`.
+    pub fn reflect_slash(self) -> Self {
+        let (dx, dy) = self.offset();
+        Self::from_offset(-dy, -dx).unwrap()
+    }
+
+    /// Direction after being reflected at a mirror shaped like `\`.
+    pub fn reflect_backslash(self) -> Self {
+        let (dx, dy) = self.offset();
+        Self::from_offset(dy, dx).unwrap()
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::E | Direction::W)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::N | Direction::S)
+    }
+
+    /// The step in this direction as (dx, dy).
+    pub fn offset(self) -> (i8, i8) {
+        match self {
+            Direction::N => (0, -1),
+            Direction::NE => (1, -1),
+            Direction::E => (1, 0),
+            Direction::SE => (1, 1),
+            Direction::S => (0, 1),
+            Direction::SW => (-1, 1),
+            Direction::W => (-1, 0),
+            Direction::NW => (-1, -1),
+        }
+    }
+
+    fn from_offset(dx: i8, dy: i8) -> Option<Self> {
+        Self::ALL.into_iter().find(|direction| direction.offset() == (dx, dy))
+    }
+
+    pub fn vector<T: SignedCoord + From<i8>>(self) -> Vector<T> {
+        let (dx, dy) = self.offset();
+        Vector { dx: T::from(dx), dy: T::from(dy) }
+    }
+
+    /// The direction of `vector`, if it is orthogonal or diagonal.
+    pub fn of_vector<T: SignedCoord>(vector: Vector<T>) -> Option<Self> {
+        let zero = T::default();
+        let (dx, dy) = (vector.dx.cmp(&zero) as i8, vector.dy.cmp(&zero) as i8);
+        let direction = Self::from_offset(dx, dy)?;
+        let (abs_dx, abs_dy) = (vector.dx.abs_diff(zero), vector.dy.abs_diff(zero));
+        (dx == 0 || dy == 0 || abs_dx == abs_dy).then_some(direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point::new(1usize, 6), Point::new(5usize, 2));
+        assert_eq!(a.manhattan(b), 8);
+        assert_eq!(b.manhattan(a), 8);
+        assert_eq!(Point::new(-1i32, 2).chebyshev(Point::new(2, 0)), 3);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let point = Point::new(2i32, 3);
+        let vector = Vector { dx: -1, dy: 4 };
+        assert_eq!(point + vector, Point::new(1, 7));
+        assert_eq!(point - vector, Point::new(3, -1));
+        assert_eq!(Point::new(1, 7) - point, vector);
+        assert_eq!(vector * 2 + -vector, vector);
+        assert_eq!(point.checked_add(vector), Some(Point::new(1, 7)));
+        assert_eq!(point.checked_sub(vector), Some(Point::new(3, -1)));
+        assert_eq!(Point::new(i32::MAX, 0).checked_sub(vector), None);
+        assert_eq!(Point::new(1usize, 0).checked_sub(Vector { dx: 1, dy: 1 }), None);
+    }
+
+    #[test]
+    fn steps() {
+        let origin = Point::new(0usize, 0);
+        assert_eq!(origin.step(Direction::SE), Some(Point::new(1, 1)));
+        assert_eq!(origin.step(Direction::N), None);
+        assert_eq!(Point::new(0i32, 0).step(Direction::NW), Some(Point::new(-1, -1)));
+        assert_eq!(Point::new(u8::MAX, 0).step(Direction::E), None);
+    }
+
+    #[test]
+    fn casts() {
+        assert_eq!(Point::new(3i32, 4).try_cast::<usize>(), Some(Point::new(3usize, 4)));
+        assert_eq!(Point::new(-1i32, 4).try_cast::<usize>(), None);
+        assert_eq!(Point::new(300usize, 0).try_cast::<u8>(), None);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(Direction::NE.turn_left(), Direction::NW);
+        assert_eq!(Direction::SW.opposite(), Direction::NE);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reflect_slash().reflect_slash(), direction);
+        }
+    }
+
+    #[test]
+    fn reflections() {
+        // A beam heading east is deflected north by '/' and south by '\'
+        assert_eq!(Direction::E.reflect_slash(), Direction::N);
+        assert_eq!(Direction::E.reflect_backslash(), Direction::S);
+        assert_eq!(Direction::N.reflect_slash(), Direction::E);
+        assert_eq!(Direction::W.reflect_backslash(), Direction::N);
+    }
+
+    #[test]
+    fn vectors() {
+        assert_eq!(Direction::SW.vector::<i64>(), Vector { dx: -1, dy: 1 });
+        assert_eq!(Direction::of_vector(Vector { dx: 0, dy: -5 }), Some(Direction::N));
+        assert_eq!(Direction::of_vector(Vector { dx: 3, dy: 3 }), Some(Direction::SE));
+        assert_eq!(Direction::of_vector(Vector { dx: 1, dy: 2 }), None);
+        assert_eq!(Direction::of_vector(Vector { dx: 0, dy: 0 }), None);
+    }
+}
@@ -0,0 +1,229 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::{Direction, Point, Vector};
+
+/// A rectangular 2D map. Cells are addressed by [`Point`]s, with `x` the column and `y` the row,
+/// starting at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics if `rows` is empty or not rectangular.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        assert!(!rows.is_empty() && !rows[0].is_empty(), "A grid needs at least one cell");
+        let width = rows[0].len();
+        let height = rows.len();
+        assert!(rows.iter().all(|row| row.len() == width), "All rows of a grid need the same length");
+        Self { width, height, cells: rows.into_iter().flatten().collect() }
+    }
+
+    pub fn from_fn<F: FnMut(Point<usize>) -> T>(width: usize, height: usize, mut f: F) -> Self {
+        assert!(width > 0 && height > 0, "A grid needs at least one cell");
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| Point::new(x, y))).map(&mut f).collect();
+        Self { width, height, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point<usize>) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    /// The position `point` if it lies within the grid, for points of any coordinate type.
+    pub fn pos<C: Copy>(&self, point: Point<C>) -> Option<Point<usize>>
+        where usize: TryFrom<C>,
+    {
+        let pos = Point { x: usize::try_from(point.x).ok()?, y: usize::try_from(point.y).ok()? };
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The position `offset` away from `pos`, if it lies within the grid.
+    pub fn offset(&self, pos: Point<usize>, offset: Vector<isize>) -> Option<Point<usize>> {
+        self.pos(pos.try_cast::<isize>()?.checked_add(offset)?)
+    }
+
+    pub fn get(&self, pos: Point<usize>) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Point<usize>) -> Option<&mut T> {
+        self.contains(pos).then(|| &mut self.cells[pos.y * self.width + pos.x])
+    }
+
+    /// Positions of the orthogonal neighbours of `pos` within the grid, clockwise starting with the one above.
+    pub fn neighbours4(&self, pos: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction::ORTHOGONAL.into_iter().filter_map(move |direction| pos.step(direction).filter(|pos| self.contains(*pos)))
+    }
+
+    /// Positions of the orthogonal and diagonal neighbours of `pos` within the grid, clockwise starting with the one above.
+    pub fn neighbours8(&self, pos: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| pos.step(direction).filter(|pos| self.contains(*pos)))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "Column {col} is out of bounds");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell for which `predicate` holds, row by row.
+    pub fn position<P: Fn(&T) -> bool>(&self, predicate: P) -> Option<Point<usize>> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self::from_fn(width, height, |_| value.clone())
+    }
+
+    /// Mirrors the grid at its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |pos| self[Point::new(pos.y, pos.x)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |pos| self[Point::new(pos.y, self.height - 1 - pos.x)].clone())
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |pos| self[Point::new(self.width - 1 - pos.y, pos.x)].clone())
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.width, self.height, |pos| self[Point::new(self.width - 1 - pos.x, pos.y)].clone())
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.width, self.height, |pos| self[Point::new(pos.x, self.height - 1 - pos.y)].clone())
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point<usize>) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("Position {pos:?} is outside of the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Point<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| panic!("Position {pos:?} is outside of the {width}x{height} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn indexing() {
+        let mut grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(0, 1)], 4);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        grid[Point::new(2, 0)] = 7;
+        assert_eq!(grid.row(0), [1, 2, 7]);
+        assert_eq!(grid.pos(Point::new(0, -1)), None);
+        assert_eq!(grid.pos(Point::new(2i64, 1)), Some(Point::new(2, 1)));
+        assert_eq!(grid.offset(Point::new(2, 1), Vector { dx: -2, dy: -1 }), Some(Point::new(0, 0)));
+        assert_eq!(grid.offset(Point::new(2, 1), Vector { dx: 1, dy: 0 }), None);
+        assert_eq!(grid.offset(Point::new(0, 0), Vector { dx: isize::MIN, dy: 0 }), None);
+        assert_eq!(grid.position(|cell| *cell > 4), Some(Point::new(2, 0)));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+        assert_eq!(grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(), [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 3);
+        assert_eq!(
+            grid.neighbours8(Point::new(1, 0)).collect::<Vec<_>>(),
+            [Point::new(2, 0), Point::new(2, 1), Point::new(1, 1), Point::new(0, 1), Point::new(0, 0)]
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.columns().map(|col| col.sum::<u32>()).collect::<Vec<_>>(), [5, 7, 9]);
+        assert_eq!(grid.iter().nth(4), Some((Point::new(1, 1), &5)));
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = grid();
+        assert_eq!(grid.transpose(), Grid::from_rows(vec![vec![1, 4], vec![2, 5], vec![3, 6]]));
+        assert_eq!(grid.rotate_clockwise(), Grid::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]]));
+        assert_eq!(grid.rotate_counterclockwise(), Grid::from_rows(vec![vec![3, 6], vec![2, 5], vec![1, 4]]));
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.flip_horizontal(), Grid::from_rows(vec![vec![3, 2, 1], vec![6, 5, 4]]));
+        assert_eq!(grid.flip_vertical(), Grid::from_rows(vec![vec![4, 5, 6], vec![1, 2, 3]]));
+        assert_eq!(grid.map(|cell| cell % 2), Grid::from_rows(vec![vec![1, 0, 1], vec![0, 1, 0]]));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(grid().to_string(), "123\n456\n");
+    }
+}
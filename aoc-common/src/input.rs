@@ -0,0 +1,137 @@
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, prelude::*};
+use std::path::PathBuf;
+use std::str::Utf8Error;
+
+/// Directory holding one `dayNN/input.txt` per day, used when no input path is given.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Path of the puzzle input to use for `day` unless one is passed explicitly:
+/// `$AOC_INPUT_DIR/dayNN/input.txt` if the variable is set, `fallback` otherwise.
+pub fn default_input_path(day: u32, fallback: &str) -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir).join(format!("day{day:02}")).join("input.txt"),
+        None => PathBuf::from(fallback),
+    }
+}
+
+/// Path of `file` within the input directory of `day`, seen from the workspace root:
+/// `$AOC_INPUT_DIR/dayNN/` if the variable is set, `dayNN/` otherwise.
+pub fn day_input_path(day: u32, file: &str) -> PathBuf {
+    let dir = match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::new(),
+    };
+    dir.join(format!("day{day:02}")).join(file)
+}
+
+#[derive(Debug)]
+enum InputErrorKind {
+    Io(io::Error),
+    Utf8 { line: usize, source: Utf8Error },
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    path: String,
+    kind: InputErrorKind,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path == "-" {
+            write!(f, "Cannot read input from stdin: ")?;
+        } else {
+            write!(f, "Cannot read input file {}: ", self.path)?;
+        }
+        match &self.kind {
+            InputErrorKind::Io(e) => write!(f, "{e}"),
+            InputErrorKind::Utf8 { line, source } => write!(f, "line {line} is not valid UTF-8 ({source})"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            InputErrorKind::Io(e) => Some(e),
+            InputErrorKind::Utf8 { source, .. } => Some(source),
+        }
+    }
+}
+
+fn read_text(path: &str) -> Result<String, InputError> {
+    let error = |kind| InputError { path: path.to_string(), kind };
+
+    let mut bytes = Vec::new();
+    let read = if path == "-" {
+        io::stdin().lock().read_to_end(&mut bytes)
+    } else {
+        File::open(path).and_then(|mut file| file.read_to_end(&mut bytes))
+    };
+    read.map_err(|e| error(InputErrorKind::Io(e)))?;
+
+    match String::from_utf8(bytes) {
+        Ok(text) => Ok(text),
+        Err(e) => {
+            let source = e.utf8_error();
+            let line = e.as_bytes()[..source.valid_up_to()].iter().filter(|b| **b == b'\n').count() + 1;
+            Err(error(InputErrorKind::Utf8 { line, source }))
+        }
+    }
+}
+
+/// Splits `text` into lines, dropping a UTF-8 byte order mark and the `\r` of CRLF line endings.
+pub fn normalize_lines(text: &str) -> Vec<String> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    text.lines().map(String::from).collect()
+}
+
+/// Reads the lines of the file at `path`, or of stdin if `path` is `-`.
+pub fn read_lines(path: &str) -> Result<Vec<String>, InputError> {
+    Ok(normalize_lines(&read_text(path)?))
+}
+
+/// The lines of `text` like [`normalize_lines`], joined by `\n`. If `strip_trailing_blank`, an empty
+/// last line, as left by a copy and paste with one newline too many, is dropped.
+pub fn normalize_input(text: &str, strip_trailing_blank: bool) -> String {
+    let mut lines = normalize_lines(text);
+    if strip_trailing_blank && lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+/// Reads the puzzle input at `path`, see [`normalize_input`].
+pub fn read_input(path: &str, strip_trailing_blank: bool) -> Result<String, InputError> {
+    Ok(normalize_input(&read_text(path)?, strip_trailing_blank))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_lines("\u{feff}a\r\nb\r\n"), ["a", "b"]);
+        assert_eq!(normalize_lines("a\n\nb"), ["a", "", "b"]);
+        assert_eq!(normalize_input("\u{feff}a\r\nb\r\n\r\n", true), "a\nb");
+    }
+
+    #[test]
+    fn trailing_blank_lines() {
+        let path = env::temp_dir().join(format!("aoc-input-{}.txt", std::process::id()));
+        let read = |text: &str, strip_trailing_blank| {
+            std::fs::write(&path, text).unwrap();
+            read_input(path.to_str().unwrap(), strip_trailing_blank).unwrap()
+        };
+        assert_eq!(read("a\nb\n\n", true), "a\nb");
+        assert_eq!(read("a\nb\n\n", false), "a\nb\n");
+        assert_eq!(read("a\nb\n\n\n", true), "a\nb\n");
+        assert_eq!(read("a\n \n", true), "a\n ");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
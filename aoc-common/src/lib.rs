@@ -0,0 +1,29 @@
+//! Helpers shared by all days.
+
+mod checked;
+mod config;
+mod differential;
+mod explain;
+mod geometry;
+mod grid;
+mod input;
+mod logging;
+mod parse;
+mod report;
+mod rng;
+mod solution;
+mod visual;
+
+pub use checked::{checked_add, checked_mul, checked_product, checked_sub, checked_sum, CheckedInt};
+pub use config::{init_config, param, Config, Param, ParamKind, CONFIG_PATH, CONFIG_VAR};
+pub use differential::{assert_matches_oracle, reproducer_dir, REPRODUCER_DIR_VAR};
+pub use explain::{render_explanations, render_explanations_json, Explanation};
+pub use geometry::{Coord, Direction, Point, SignedCoord, Vector};
+pub use grid::Grid;
+pub use input::{day_input_path, default_input_path, normalize_input, normalize_lines, read_input, read_lines, InputError, INPUT_DIR_VAR};
+pub use logging::{init_logging, log_level, LOG_VAR};
+pub use parse::{parse_grid, parse_lines, parse_number, ParseError};
+pub use report::{input_hash, render_csv, render_json, time, Format, Record, Status};
+pub use rng::{Rng, Uniform};
+pub use solution::{run, Day, Parsed, Solution};
+pub use visual::{is_recording, play_ansi, record, render_ansi, render_html, render_picture, render_svg, start_recording, stop_recording, xml_escape, Color, Divider, Frame, Recorder};
@@ -0,0 +1,64 @@
+use std::env;
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Variable to set the log level (`off`, `error`, `warn`, `info`, `debug` or `trace`)
+/// when no `-v` flag is given.
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// Writes the messages of the `log` macros to stderr, so they never mix with the answers on stdout.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{:<5} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// The log level for `verbosity` `-v` flags: info, debug and trace for one, two and
+/// three or more. Without flags it is `env_level`, the value of [`LOG_VAR`], or warn if unset.
+pub fn log_level(verbosity: u8, env_level: Option<&str>) -> Result<LevelFilter, String> {
+    match (verbosity, env_level) {
+        (0, None) => Ok(LevelFilter::Warn),
+        (0, Some(level)) => level.trim().parse()
+            .map_err(|_| format!("Invalid {LOG_VAR} \"{level}\", expected off, error, warn, info, debug or trace")),
+        (1, _) => Ok(LevelFilter::Info),
+        (2, _) => Ok(LevelFilter::Debug),
+        _ => Ok(LevelFilter::Trace),
+    }
+}
+
+/// Sends the diagnostics of all days to stderr, at the level given by [`log_level`].
+pub fn init_logging(verbosity: u8) -> Result<(), String> {
+    let level = log_level(verbosity, env::var(LOG_VAR).ok().as_deref())?;
+    // Fails only if a logger is set already, which then keeps working
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(level);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels() {
+        assert_eq!(log_level(0, None), Ok(LevelFilter::Warn));
+        assert_eq!(log_level(0, Some("debug")), Ok(LevelFilter::Debug));
+        assert_eq!(log_level(0, Some("OFF")), Ok(LevelFilter::Off));
+        assert!(log_level(0, Some("loud")).is_err());
+        assert_eq!(log_level(1, Some("off")), Ok(LevelFilter::Info));
+        assert_eq!(log_level(2, None), Ok(LevelFilter::Debug));
+        assert_eq!(log_level(5, None), Ok(LevelFilter::Trace));
+    }
+}
@@ -0,0 +1,178 @@
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+
+use crate::Grid;
+
+/// Error in the puzzle input, pointing at the offending part of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    // Line and column are 1-based, column counts chars
+    line: Option<usize>,
+    column: Option<usize>,
+    width: usize,
+    text: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into(), line: None, column: None, width: 1, text: None }
+    }
+
+    /// Points the error at `span`, which has to be a slice of `line`.
+    pub fn at(mut self, line: &str, span: &str) -> Self {
+        let line_start = line.as_ptr() as usize;
+        let span_start = span.as_ptr() as usize;
+        if line_start <= span_start && span_start + span.len() <= line_start + line.len() {
+            let offset = span_start - line_start;
+            self.column = Some(line[..offset].chars().count() + 1);
+            self.width = span.chars().count().max(1);
+        }
+        self.text = Some(line.to_string());
+        self
+    }
+
+    /// Points the error at the char with index `index` in `line`.
+    pub fn at_index(mut self, line: &str, index: usize) -> Self {
+        self.column = Some(index + 1);
+        self.width = 1;
+        self.text = Some(line.to_string());
+        self
+    }
+
+    /// Sets the number of the offending line, unless it is already known.
+    pub fn on_line(mut self, line_no: usize, text: &str) -> Self {
+        if self.line.is_none() {
+            self.line = Some(line_no);
+            self.text.get_or_insert_with(|| text.to_string());
+        }
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    /// Multi-line description of the error, quoting the offending line and
+    /// marking the offending part with carets.
+    pub fn diagnostic(&self) -> String {
+        let mut res = format!("error: {}\n", self.message);
+        let (Some(line), Some(text)) = (self.line, &self.text) else {
+            return res;
+        };
+        let gutter = " ".repeat(line.to_string().len());
+        match self.column {
+            Some(column) => writeln!(res, "{gutter}--> line {line}, column {column}").unwrap(),
+            None => writeln!(res, "{gutter}--> line {line}").unwrap(),
+        }
+        writeln!(res, "{gutter} |").unwrap();
+        writeln!(res, "{line} | {text}").unwrap();
+        if let Some(column) = self.column {
+            writeln!(res, "{gutter} | {}{}", " ".repeat(column - 1), "^".repeat(self.width)).unwrap();
+        }
+        res
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: {}", self.message),
+            (Some(line), None) => write!(f, "line {line}: {}", self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, which has to be a slice of `line`, as a number.
+pub fn parse_number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::new(format!("Expected a number, found \"{token}\"")).at(line, token))
+}
+
+/// Parses every line of `input` with `parse_line`, adding the line number to errors.
+pub fn parse_lines<T, F>(input: &str, parse_line: F) -> Result<Vec<T>, ParseError>
+    where
+        F: Fn(&str) -> Result<T, ParseError>,
+{
+    input.lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.on_line(i + 1, line)))
+        .collect()
+}
+
+/// Parses a rectangular grid made up of the chars in `allowed`.
+/// `first_line_no` is the number of the first line of `input` within the whole input.
+pub fn parse_grid(input: &str, allowed: &str, first_line_no: usize) -> Result<Grid<char>, ParseError> {
+    let mut grid: Vec<Vec<char>> = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let row: Vec<char> = line.chars().collect();
+        if let Some(index) = row.iter().position(|c| !allowed.contains(*c)) {
+            return Err(ParseError::new(format!("Unexpected character '{}', expected one of \"{allowed}\"", row[index]))
+                .at_index(line, index)
+                .on_line(first_line_no + i, line));
+        }
+        if let Some(first) = grid.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::new(format!("Expected a row of length {}, found length {}", first.len(), row.len()))
+                    .at_index(line, row.len().min(first.len()))
+                    .on_line(first_line_no + i, line));
+            }
+        }
+        grid.push(row);
+    }
+    if grid.is_empty() || grid[0].is_empty() {
+        return Err(ParseError::new("Expected a grid, found nothing").on_line(first_line_no, ""));
+    }
+    Ok(Grid::from_rows(grid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number() {
+        assert_eq!(parse_number::<u32>("a 12 b", "12"), Ok(12));
+        let line = "seeds: 1 x3";
+        let error = parse_number::<u32>(line, &line[9..]).unwrap_err();
+        assert_eq!(error.column(), Some(10));
+        assert_eq!(error.message(), "Expected a number, found \"x3\"");
+    }
+
+    #[test]
+    fn lines_add_line_number() {
+        let error = parse_lines("1\n2\nx", |line| parse_number::<u32>(line, line)).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: Expected a number, found \"x\"");
+    }
+
+    #[test]
+    fn diagnostic() {
+        let line = "Game 1: 3 blau";
+        let error = ParseError::new("Unknown color").at(line, &line[10..]).on_line(4, line);
+        assert_eq!(error.diagnostic(), "error: Unknown color\n --> line 4, column 11\n  |\n4 | Game 1: 3 blau\n  |           ^^^^\n");
+    }
+
+    #[test]
+    fn grid() {
+        assert_eq!(parse_grid(".#\n#.", ".#", 1), Ok(Grid::from_rows(vec![vec!['.', '#'], vec!['#', '.']])));
+
+        let error = parse_grid(".#\n.x", ".#", 5).unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(6), Some(2)));
+
+        let error = parse_grid(".#\n.", ".#", 1).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: Expected a row of length 2, found length 1");
+
+        assert!(parse_grid("", ".#", 1).is_err());
+    }
+}
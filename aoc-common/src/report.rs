@@ -0,0 +1,212 @@
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+/// Output format of answers and timings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable output, which may change at any time
+    #[default]
+    Text,
+    /// A JSON array with one object per part, see [`Record`]
+    Json,
+    /// A CSV table with a header and one row per part, see [`Record`]
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("expected one of \"text\", \"json\" or \"csv\", got \"{s}\"")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    NotSolved,
+    MissingInput,
+    /// The input could not be read or parsed, or the solution panicked
+    Failed,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::NotSolved => "not_solved",
+            Status::MissingInput => "missing_input",
+            Status::Failed => "failed",
+        }
+    }
+}
+
+/// Result of one part of one day, with the fields `day`, `part`, `status`, `answer`,
+/// `elapsed_ns`, `input_hash` and `error` in the machine-readable formats.
+/// `answer` and `elapsed_ns` are only set for solved parts, `input_hash` only if there is an input
+/// and `error` only for failed parts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub status: Status,
+    pub answer: Option<String>,
+    #[serde(rename = "elapsed_ns", serialize_with = "serialize_nanos")]
+    pub elapsed: Option<Duration>,
+    pub input_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn solved(day: u32, part: u32, answer: String, elapsed: Duration, input_hash: &str) -> Self {
+        Self {
+            day,
+            part,
+            status: Status::Solved,
+            answer: Some(answer),
+            elapsed: Some(elapsed),
+            input_hash: Some(input_hash.to_string()),
+            error: None,
+        }
+    }
+
+    pub fn not_solved(day: u32, part: u32, input_hash: &str) -> Self {
+        Self {
+            day,
+            part,
+            status: Status::NotSolved,
+            answer: None,
+            elapsed: None,
+            input_hash: Some(input_hash.to_string()),
+            error: None,
+        }
+    }
+
+    pub fn missing_input(day: u32, part: u32) -> Self {
+        Self { day, part, status: Status::MissingInput, answer: None, elapsed: None, input_hash: None, error: None }
+    }
+
+    /// `input_hash` is `None` if the input could not be read.
+    pub fn failed(day: u32, part: u32, error: &str, input_hash: Option<&str>) -> Self {
+        Self {
+            day,
+            part,
+            status: Status::Failed,
+            answer: None,
+            elapsed: None,
+            input_hash: input_hash.map(str::to_string),
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+fn serialize_nanos<S: Serializer>(elapsed: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+    elapsed.map(|elapsed| elapsed.as_nanos()).serialize(serializer)
+}
+
+/// Calls `f`, measuring how long it takes.
+pub fn time<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Stable hash of the input text, to tell apart results for different inputs.
+/// This is the 64 bit FNV-1a hash in hex, which does not depend on the Rust version.
+pub fn input_hash(text: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{hash:016x}")
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Renders `records` as a JSON array of objects.
+pub fn render_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).unwrap() + "\n"
+}
+
+/// Renders `records` as a CSV table with a header.
+pub fn render_csv(records: &[Record]) -> String {
+    let mut res = String::from("day,part,status,answer,elapsed_ns,input_hash,error\n");
+    for record in records {
+        writeln!(
+            res,
+            "{},{},{},{},{},{},{}",
+            record.day,
+            record.part,
+            record.status.name(),
+            record.answer.as_deref().map_or_else(String::new, csv_field),
+            record.elapsed.map_or_else(String::new, |elapsed| elapsed.as_nanos().to_string()),
+            record.input_hash.as_deref().unwrap_or(""),
+            record.error.as_deref().map_or_else(String::new, csv_field),
+        ).unwrap();
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record::solved(7, 1, String::from("6440"), Duration::from_micros(12), "00ff"),
+            Record::not_solved(12, 2, "00ff"),
+            Record::missing_input(13, 1),
+            Record::failed(10, 1, "Could not find start marker!", Some("00ff")),
+        ]
+    }
+
+    #[test]
+    fn hash_is_fnv1a() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn json() {
+        let json: serde_json::Value = serde_json::from_str(&render_json(&records())).unwrap();
+        assert_eq!(json[0], serde_json::json!({
+            "day": 7, "part": 1, "status": "solved", "answer": "6440", "elapsed_ns": 12000, "input_hash": "00ff", "error": null
+        }));
+        assert_eq!(json[1]["status"], "not_solved");
+        assert_eq!(json[2]["input_hash"], serde_json::Value::Null);
+        assert_eq!(json[3]["error"], "Could not find start marker!");
+        assert_eq!(render_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(render_csv(&records()), "day,part,status,answer,elapsed_ns,input_hash,error\n\
+            7,1,solved,6440,12000,00ff,\n\
+            12,2,not_solved,,,00ff,\n\
+            13,1,missing_input,,,,\n\
+            10,1,failed,,,00ff,Could not find start marker!\n");
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
+    #[test]
+    fn format_from_str() {
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
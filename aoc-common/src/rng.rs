@@ -0,0 +1,116 @@
+use std::ops::RangeInclusive;
+
+/// Integer type that [`Rng::range`] can draw.
+pub trait Uniform: Copy {
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_uniform {
+    ($($t:ty),*) => {
+        $(
+            impl Uniform for $t {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_uniform!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Small pseudo random number generator (SplitMix64) for generating puzzle inputs.
+/// The numbers only depend on the seed, also across platforms and Rust versions.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number within `range`, panics if it is empty.
+    pub fn range<T: Uniform>(&mut self, range: RangeInclusive<T>) -> T {
+        let (start, end) = (range.start().to_i128(), range.end().to_i128());
+        assert!(start <= end, "Cannot draw a number from an empty range");
+        // Scales the random bits to the length of the range, which has at most 2^64 values
+        let len = (end - start + 1) as u128;
+        let offset = (self.next_u64() as u128 * len) >> 64;
+        T::from_i128(start + offset as i128)
+    }
+
+    /// An index into a slice of length `len`, panics if it is 0.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "Cannot draw an index of an empty slice");
+        self.range(0..=len - 1)
+    }
+
+    /// `true` with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splitmix64() {
+        // Reference values of SplitMix64 with seed 0
+        let mut rng = Rng::new(0);
+        assert_eq!(rng.next_u64(), 0xe220a8397b1dcdaf);
+        assert_eq!(rng.next_u64(), 0x6e789e6aa1b965f4);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(42);
+        for _ in 0..1000 {
+            let value = rng.range(-3i32..=5);
+            assert!((-3..=5).contains(&value));
+        }
+        assert_eq!(rng.range(7usize..=7), 7);
+        rng.range(0..=u64::MAX);
+        rng.range(i64::MIN..=i64::MAX);
+        assert!((0..1000).any(|_| rng.range(-3i32..=5) == -3));
+        assert!((0..1000).any(|_| rng.range(-3i32..=5) == 5));
+    }
+
+    #[test]
+    fn shuffle_keeps_items() {
+        let mut rng = Rng::new(1);
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+}
@@ -0,0 +1,224 @@
+use std::env;
+use std::fmt::Display;
+use std::process;
+
+use crate::{default_input_path, init_config, init_logging, input_hash, read_input, render_csv, render_json, time, Explanation, Format, Frame, Param, ParseError, Record, Rng};
+
+/// A day's puzzle: parse the input once, then compute both answers from it.
+pub trait Solution {
+    const DAY: u32;
+
+    /// The parameters the day reads with [`param`](crate::param), checked when the config is loaded.
+    const PARAMS: &'static [Param] = &[];
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    /// Part 2 is only revealed after solving part 1, so it might not be solved yet.
+    fn part2(_input: &Self::Input) -> Option<impl Display> {
+        None::<u64>
+    }
+
+    /// A random valid input, which only depends on the state of `rng`. `size` scales
+    /// the input, e.g. the number of lines or the width of a map, see the days for details.
+    /// `None` if there is no generator for this day.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// Annotated pictures of the solved puzzle, drawn from the solver's own results,
+    /// see [`render_picture`](crate::render_picture). Empty if this day draws none.
+    fn pictures(_input: &Self::Input) -> Vec<Frame> {
+        Vec::new()
+    }
+
+    /// How the answers come about, line by line of the input. Empty if this day explains nothing.
+    fn explain(_input: &Self::Input) -> Vec<Explanation> {
+        Vec::new()
+    }
+}
+
+/// Parsed input of some solution, with the answers turned into strings.
+pub trait Parsed {
+    fn part1(&self) -> String;
+    fn part2(&self) -> Option<String>;
+    fn pictures(&self) -> Vec<Frame>;
+    fn explain(&self) -> Vec<Explanation>;
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn part1(&self) -> String {
+        S::part1(&self.0).to_string()
+    }
+
+    fn part2(&self) -> Option<String> {
+        S::part2(&self.0).map(|answer| answer.to_string())
+    }
+
+    fn pictures(&self) -> Vec<Frame> {
+        S::pictures(&self.0)
+    }
+
+    fn explain(&self) -> Vec<Explanation> {
+        S::explain(&self.0)
+    }
+}
+
+/// Type-erased handle to a [`Solution`], so that days can be kept in one list.
+pub struct Day {
+    pub number: u32,
+    pub params: &'static [Param],
+    pub parse: fn(&str) -> Result<Box<dyn Parsed>, ParseError>,
+    pub generate: fn(&mut Rng, usize) -> Option<String>,
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>, ParseError>
+    where
+        S::Input: 'static,
+{
+    Ok(Box::new(ParsedInput::<S>(S::parse(input)?)))
+}
+
+impl Day {
+    pub const fn of<S: Solution + 'static>() -> Self
+        where
+            S::Input: 'static,
+    {
+        Self { number: S::DAY, params: S::PARAMS, parse: parse_boxed::<S>, generate: S::generate }
+    }
+}
+
+/// Arguments of the binaries of the single days.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Args {
+    path: Option<String>,
+    format: Format,
+    /// The number of `v`s
+    verbosity: u8,
+    /// Config overrides like `day02.red=20`
+    overrides: Vec<String>,
+}
+
+/// Parses `[-v...] [--format text|json|csv] [--set DAY.NAME=VALUE...] [INPUT]`.
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut args = args;
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        if let Some(vs) = arg.strip_prefix('-').filter(|vs| !vs.is_empty() && vs.chars().all(|char| char == 'v')) {
+            parsed.verbosity = parsed.verbosity.saturating_add(vs.len() as u8);
+        } else if arg == "--format" {
+            parsed.format = args.next().ok_or("--format needs a value")?.parse()?;
+        } else if let Some(value) = arg.strip_prefix("--format=") {
+            parsed.format = value.parse()?;
+        } else if arg == "--set" {
+            parsed.overrides.push(args.next().ok_or("--set needs a value")?);
+        } else if let Some(value) = arg.strip_prefix("--set=") {
+            parsed.overrides.push(value.to_string());
+        } else if (arg.starts_with('-') && arg != "-") || parsed.path.is_some() {
+            return Err(format!(
+                "Unexpected argument \"{arg}\", usage: [-v...] [--format text|json|csv] [--set DAY.NAME=VALUE...] [INPUT]"
+            ));
+        } else {
+            parsed.path = Some(arg);
+        }
+    }
+    Ok(parsed)
+}
+
+/// Entry point of the binaries of the single days: solves both parts and prints the answers.
+///
+/// The input is read from the path given as argument (`-` for stdin), falling back
+/// to [`default_input_path`] with `input.txt` in the current directory.
+/// With `--format json` or `--format csv` the answers are printed as [`Record`]s.
+/// Diagnostics go to stderr, more of them with every `-v`, see [`init_logging`].
+/// Puzzle parameters come from `aoc.toml` and `--set` overrides, see [`init_config`].
+pub fn run<S: Solution>() {
+    let Args { path, format, verbosity, overrides } = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(2);
+        }
+    };
+    if let Err(e) = init_logging(verbosity).and_then(|_| init_config(&overrides, |config| config.validate(S::DAY, S::PARAMS))) {
+        eprintln!("{e}");
+        process::exit(2);
+    }
+    let path = path.unwrap_or_else(|| default_input_path(S::DAY, "input.txt").to_string_lossy().into_owned());
+    let text = match read_input(&path, true) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+    let input = match S::parse(&text) {
+        Ok(input) => input,
+        Err(e) => {
+            eprint!("{}", e.diagnostic());
+            process::exit(1);
+        }
+    };
+    let (part1, elapsed1) = time(|| S::part1(&input).to_string());
+    let (part2, elapsed2) = time(|| S::part2(&input).map(|answer| answer.to_string()));
+
+    let hash = input_hash(&text);
+    let mut records = vec![Record::solved(S::DAY, 1, part1, elapsed1, &hash)];
+    records.push(match part2 {
+        Some(answer) => Record::solved(S::DAY, 2, answer, elapsed2, &hash),
+        None => Record::not_solved(S::DAY, 2, &hash),
+    });
+    match format {
+        Format::Text => {
+            for record in records {
+                if let Some(answer) = record.answer {
+                    println!("Part {}: {answer}", record.part);
+                }
+            }
+        }
+        Format::Json => print!("{}", render_json(&records)),
+        Format::Csv => print!("{}", render_csv(&records)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn parsed(path: Option<&str>, format: Format, verbosity: u8, overrides: &[&str]) -> Result<Args, String> {
+        Ok(Args {
+            path: path.map(String::from),
+            format,
+            verbosity,
+            overrides: overrides.iter().map(|o| o.to_string()).collect(),
+        })
+    }
+
+    #[test]
+    fn day_args() {
+        assert_eq!(args(&[]), parsed(None, Format::Text, 0, &[]));
+        assert_eq!(args(&["-"]), parsed(Some("-"), Format::Text, 0, &[]));
+        assert_eq!(args(&["--format", "json", "in.txt"]), parsed(Some("in.txt"), Format::Json, 0, &[]));
+        assert_eq!(args(&["in.txt", "--format=csv"]), parsed(Some("in.txt"), Format::Csv, 0, &[]));
+        assert!(args(&["--format"]).is_err());
+        assert!(args(&["--format", "xml"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+        assert_eq!(args(&["-v", "in.txt", "-vv"]), parsed(Some("in.txt"), Format::Text, 3, &[]));
+        assert!(args(&["--verbose"]).is_err());
+        assert!(args(&["-vx"]).is_err());
+        assert_eq!(
+            args(&["--set", "day02.red=20", "--set=day02.blue=3"]),
+            parsed(None, Format::Text, 0, &["day02.red=20", "day02.blue=3"])
+        );
+        assert!(args(&["--set"]).is_err());
+    }
+}
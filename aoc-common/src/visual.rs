@@ -0,0 +1,434 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::{Grid, Point};
+
+/// Colors to mark cells of a [`Frame`] with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+
+    fn css(self) -> &'static str {
+        match self {
+            Color::Red => "#e5534b",
+            Color::Green => "#57ab5a",
+            Color::Yellow => "#c69026",
+            Color::Blue => "#539bf5",
+            Color::Magenta => "#b083f0",
+            Color::Cyan => "#39c5cf",
+        }
+    }
+}
+
+/// A line between two rows or two columns of a [`Frame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Divider {
+    /// The line above the row
+    Row(usize),
+    /// The line left of the column
+    Col(usize),
+}
+
+/// Snapshot of a grid simulation: the characters of the grid, some cells marked
+/// with a color, and a caption like the step number.
+///
+/// Pictures of a solved puzzle can also shade cells, draw dividers and explain the
+/// colors in a legend, which are only shown in SVG.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub grid: Grid<char>,
+    pub marks: HashMap<Point<usize>, Color>,
+    pub caption: String,
+    /// Colors with an opacity between 0 and 1, drawn below the marks
+    pub shades: HashMap<Point<usize>, (Color, f64)>,
+    pub dividers: Vec<(Divider, Color)>,
+    pub legend: Vec<(Color, String)>,
+}
+
+impl Frame {
+    pub fn new(grid: Grid<char>) -> Self {
+        Self {
+            grid,
+            marks: HashMap::new(),
+            caption: String::new(),
+            shades: HashMap::new(),
+            dividers: Vec::new(),
+            legend: Vec::new(),
+        }
+    }
+
+    /// Marks the cell at `pos`, replacing an earlier mark.
+    pub fn mark(mut self, pos: Point<usize>, color: Color) -> Self {
+        self.marks.insert(pos, color);
+        self
+    }
+
+    pub fn mark_all<I: IntoIterator<Item = Point<usize>>>(mut self, positions: I, color: Color) -> Self {
+        self.marks.extend(positions.into_iter().map(|pos| (pos, color)));
+        self
+    }
+
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    /// Shades the cell at `pos`, with `opacity` clamped to 0 to 1.
+    pub fn shade(mut self, pos: Point<usize>, color: Color, opacity: f64) -> Self {
+        self.shades.insert(pos, (color, opacity.clamp(0.0, 1.0)));
+        self
+    }
+
+    pub fn divide(mut self, divider: Divider, color: Color) -> Self {
+        self.dividers.push((divider, color));
+        self
+    }
+
+    /// Explains what `color` means in the legend below the grid.
+    pub fn legend(mut self, color: Color, label: impl Into<String>) -> Self {
+        self.legend.push((color, label.into()));
+        self
+    }
+}
+
+/// Collects the frames offered by a solver, keeping at most `max_frames` of them spread evenly
+/// over the run: when full, every other frame is dropped and only every other one is taken from then on.
+#[derive(Debug)]
+pub struct Recorder {
+    frames: Vec<Frame>,
+    max_frames: usize,
+    stride: usize,
+    offered: usize,
+}
+
+impl Recorder {
+    /// Panics if `max_frames` is below 2.
+    pub fn new(max_frames: usize) -> Self {
+        assert!(max_frames >= 2, "A recorder needs room for at least two frames");
+        Self { frames: Vec::new(), max_frames, stride: 1, offered: 0 }
+    }
+
+    /// Whether the next offered frame is kept, counting it as offered.
+    fn wants_next(&mut self) -> bool {
+        let wanted = self.offered.is_multiple_of(self.stride);
+        self.offered += 1;
+        wanted
+    }
+
+    fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+        if self.frames.len() > self.max_frames {
+            self.frames = self.frames.drain(..).step_by(2).collect();
+            self.stride *= 2;
+        }
+    }
+
+    /// Offers the frame built by `frame`, which is only called if the frame is kept.
+    pub fn offer<F: FnOnce() -> Frame>(&mut self, frame: F) {
+        if self.wants_next() {
+            self.push(frame());
+        }
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames
+    }
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Starts recording the frames the solvers on this thread pass to [`record`].
+pub fn start_recording(max_frames: usize) {
+    RECORDER.with(|recorder| *recorder.borrow_mut() = Some(Recorder::new(max_frames)));
+}
+
+/// Stops recording and returns the recorded frames, none if no recording was started.
+pub fn stop_recording() -> Vec<Frame> {
+    RECORDER.with(|recorder| recorder.borrow_mut().take()).map_or_else(Vec::new, Recorder::into_frames)
+}
+
+pub fn is_recording() -> bool {
+    RECORDER.with(|recorder| recorder.borrow().is_some())
+}
+
+/// Offers a frame to the recording on this thread, see [`Recorder::offer`]. Does nothing,
+/// and does not call `frame`, unless recording, so solvers can call it on every step.
+pub fn record<F: FnOnce() -> Frame>(frame: F) {
+    // Builds the frame without holding the recorder, in case building it asks for the recorder too
+    let wanted = RECORDER.with(|recorder| recorder.borrow_mut().as_mut().is_some_and(Recorder::wants_next));
+    if wanted {
+        let frame = frame();
+        RECORDER.with(|recorder| {
+            if let Some(recorder) = recorder.borrow_mut().as_mut() {
+                recorder.push(frame);
+            }
+        });
+    }
+}
+
+/// The frame as text with ANSI colors, caption first.
+pub fn render_ansi(frame: &Frame) -> String {
+    let mut res = String::new();
+    if !frame.caption.is_empty() {
+        writeln!(res, "{}", frame.caption).unwrap();
+    }
+    for (y, line) in frame.grid.rows().enumerate() {
+        for (x, char) in line.iter().enumerate() {
+            match frame.marks.get(&Point::new(x, y)) {
+                Some(color) => write!(res, "\x1b[1;{}m{char}\x1b[0m", color.ansi()).unwrap(),
+                None => res.push(*char),
+            }
+        }
+        res.push('\n');
+    }
+    res
+}
+
+/// Plays the frames as an animation in the terminal at `fps` frames per second.
+pub fn play_ansi<W: Write>(frames: &[Frame], fps: f64, out: &mut W) -> io::Result<()> {
+    let delay = Duration::from_secs_f64(1.0 / fps);
+    for frame in frames {
+        // Clear the screen and move the cursor to its top left corner
+        write!(out, "\x1b[2J\x1b[H{}", render_ansi(frame))?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+/// Size of a cell in SVG pixels.
+const CELL: usize = 12;
+
+/// `text` with the characters that are special in XML and HTML escaped.
+pub fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// The cells, caption and legend of one frame as SVG elements, with the grid starting at `top`.
+fn svg_frame(frame: &Frame, top: usize) -> String {
+    let mut res = String::new();
+    let mut shades: Vec<_> = frame.shades.iter().collect();
+    shades.sort_by_key(|(pos, _)| (pos.y, pos.x));
+    for (pos, (color, opacity)) in shades {
+        writeln!(
+            res,
+            r#"<rect x="{}" y="{}" width="{CELL}" height="{CELL}" fill="{}" fill-opacity="{opacity:.2}"/>"#,
+            pos.x * CELL,
+            top + pos.y * CELL,
+            color.css()
+        ).unwrap();
+    }
+    let mut marks: Vec<_> = frame.marks.iter().collect();
+    marks.sort_by_key(|(pos, _)| (pos.y, pos.x));
+    for (pos, color) in marks {
+        writeln!(
+            res,
+            r#"<rect x="{}" y="{}" width="{CELL}" height="{CELL}" fill="{}"/>"#,
+            pos.x * CELL,
+            top + pos.y * CELL,
+            color.css()
+        ).unwrap();
+    }
+    for (row, line) in frame.grid.rows().enumerate() {
+        // Empty tiles are left out to keep the file small
+        let text: String = line.iter().map(|char| if *char == '.' { ' ' } else { *char }).collect();
+        if !text.trim().is_empty() {
+            writeln!(
+                res,
+                r#"<text x="0" y="{}" textLength="{}" lengthAdjust="spacing">{}</text>"#,
+                top + row * CELL + CELL - 2,
+                line.len() * CELL,
+                xml_escape(&text)
+            ).unwrap();
+        }
+    }
+    let (width, height) = (frame.grid.width() * CELL, frame.grid.height() * CELL);
+    for (divider, color) in &frame.dividers {
+        let (x1, y1, x2, y2) = match divider {
+            Divider::Row(row) => (0, top + row * CELL, width, top + row * CELL),
+            Divider::Col(col) => (col * CELL, top, col * CELL, top + height),
+        };
+        writeln!(res, r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="{}" stroke-width="2"/>"#, color.css()).unwrap();
+    }
+    if !frame.caption.is_empty() {
+        writeln!(res, r#"<text x="0" y="{}" class="caption">{}</text>"#, top - CELL + 2, xml_escape(&frame.caption)).unwrap();
+    }
+    for (i, (color, label)) in frame.legend.iter().enumerate() {
+        let y = top + height + CELL / 2 + i * CELL;
+        writeln!(res, r#"<rect x="0" y="{}" width="{}" height="{}" fill="{}"/>"#, y + 2, CELL - 4, CELL - 4, color.css()).unwrap();
+        writeln!(res, r#"<text x="{CELL}" y="{}" class="caption">{}</text>"#, y + CELL - 2, xml_escape(label)).unwrap();
+    }
+    res
+}
+
+/// Size in SVG pixels of a picture of the frame, see [`render_picture`].
+fn picture_size(frame: &Frame) -> (usize, usize) {
+    // Characters of the monospace font are about 0.6 cells wide
+    let text_width = frame.legend.iter()
+        .map(|(_, label)| CELL + label.chars().count() * CELL * 6 / 10)
+        .chain([frame.caption.chars().count() * CELL * 6 / 10])
+        .max()
+        .unwrap_or(0);
+    let legend_height = if frame.legend.is_empty() { 0 } else { CELL / 2 + frame.legend.len() * CELL };
+    (text_width.max(frame.grid.width() * CELL), 2 * CELL + frame.grid.height() * CELL + legend_height)
+}
+
+/// Opening `<svg>` tag and the style and background shared by all SVGs.
+fn svg_header(width: usize, height: usize) -> String {
+    let mut res = String::new();
+    writeln!(res, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#).unwrap();
+    writeln!(res, "<style>text {{ font-family: monospace; font-size: {}px; white-space: pre; fill: #adbac7; }} .caption {{ fill: #e6edf3; }}</style>", CELL).unwrap();
+    writeln!(res, r##"<rect width="100%" height="100%" fill="#22272e"/>"##).unwrap();
+    res
+}
+
+/// The frames as a self-contained still SVG, one below the other.
+pub fn render_picture(frames: &[Frame]) -> String {
+    let sizes: Vec<(usize, usize)> = frames.iter().map(picture_size).collect();
+    let width = sizes.iter().map(|(width, _)| *width).max().unwrap_or(0);
+    let height = sizes.iter().map(|(_, height)| height + CELL).sum::<usize>().saturating_sub(CELL);
+
+    let mut res = svg_header(width, height);
+    let mut top = 0;
+    for (frame, (_, frame_height)) in frames.iter().zip(sizes) {
+        res.push_str(&svg_frame(frame, top + 2 * CELL));
+        top += frame_height + CELL;
+    }
+    res.push_str("</svg>\n");
+    res
+}
+
+/// The frames as a single HTML page without external assets, one picture per frame.
+pub fn render_html(title: &str, frames: &[Frame]) -> String {
+    let mut res = String::new();
+    writeln!(res, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>", xml_escape(title)).unwrap();
+    writeln!(res, "<style>body {{ background: #1c2128; color: #e6edf3; font-family: sans-serif; }} svg {{ display: block; margin: 1em 0; }}</style>").unwrap();
+    writeln!(res, "</head>\n<body>\n<h1>{}</h1>", xml_escape(title)).unwrap();
+    for frame in frames {
+        res.push_str(&render_picture(std::slice::from_ref(frame)));
+    }
+    res.push_str("</body>\n</html>\n");
+    res
+}
+
+/// The frames as a self-contained animated SVG, looping at `fps` frames per second.
+pub fn render_svg(frames: &[Frame], fps: f64) -> String {
+    let width = frames.iter().map(|frame| frame.grid.width()).max().unwrap_or(0) * CELL;
+    let top = 2 * CELL;
+    let height = top + frames.iter().map(|frame| frame.grid.height()).max().unwrap_or(0) * CELL;
+    let n = frames.len();
+    let duration = n as f64 / fps;
+
+    let mut res = svg_header(width, height);
+    for (i, frame) in frames.iter().enumerate() {
+        // Each frame is only visible during its share of the loop
+        let (values, key_times) = match (i, n) {
+            (_, 1) => (String::from("visible"), String::from("0")),
+            (0, _) => (String::from("visible;hidden"), format!("0;{}", 1.0 / n as f64)),
+            _ if i == n - 1 => (String::from("hidden;visible"), format!("0;{}", i as f64 / n as f64)),
+            _ => (String::from("hidden;visible;hidden"), format!("0;{};{}", i as f64 / n as f64, (i + 1) as f64 / n as f64)),
+        };
+        writeln!(res, r#"<g visibility="{}">"#, if i == 0 { "visible" } else { "hidden" }).unwrap();
+        writeln!(
+            res,
+            r#"<animate attributeName="visibility" values="{values}" keyTimes="{key_times}" dur="{duration}s" calcMode="discrete" repeatCount="indefinite"/>"#
+        ).unwrap();
+        res.push_str(&svg_frame(frame, top));
+        res.push_str("</g>\n");
+    }
+    res.push_str("</svg>\n");
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_grid;
+
+    use super::*;
+
+    fn frame(step: usize) -> Frame {
+        Frame::new(parse_grid("#.\n.<\n", "#.<", 1).unwrap()).mark(Point::new(0, 1), Color::Red).caption(format!("step {step}"))
+    }
+
+    #[test]
+    fn keeps_frames_spread_evenly() {
+        let mut recorder = Recorder::new(4);
+        for step in 0..10 {
+            recorder.offer(|| frame(step));
+        }
+        let captions: Vec<String> = recorder.into_frames().into_iter().map(|frame| frame.caption).collect();
+        assert_eq!(captions, ["step 0", "step 4", "step 8"]);
+    }
+
+    #[test]
+    fn records_only_while_recording() {
+        record(|| panic!("Not recording, so no frame is built"));
+        start_recording(10);
+        assert!(is_recording());
+        record(|| frame(1));
+        record(|| frame(2));
+        assert_eq!(stop_recording().len(), 2);
+        assert!(!is_recording());
+        assert!(stop_recording().is_empty());
+    }
+
+    #[test]
+    fn ansi() {
+        assert_eq!(render_ansi(&frame(3)), "step 3\n#.\n\x1b[1;31m.\x1b[0m<\n");
+    }
+
+    #[test]
+    fn svg() {
+        let svg = render_svg(&[frame(1), frame(2), frame(3)], 2.0);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"24\" height=\"48\""));
+        assert_eq!(svg.matches("<g ").count(), 3);
+        assert!(svg.contains(r#"values="hidden;visible;hidden" keyTimes="0;0.3333333333333333;0.6666666666666666" dur="1.5s""#));
+        assert!(svg.contains(r##"<rect x="0" y="36" width="12" height="12" fill="#e5534b"/>"##));
+        assert!(svg.contains("># </text>") && svg.contains("> &lt;</text>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn picture() {
+        let picture = frame(1)
+            .shade(Point::new(1, 0), Color::Blue, 0.5)
+            .divide(Divider::Col(1), Color::Green)
+            .legend(Color::Red, "arrow");
+        let svg = render_picture(&[picture.clone(), frame(2)]);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"48\" height=\"126\""), "{svg}");
+        assert!(!svg.contains("<animate"));
+        assert!(svg.contains(r##"<rect x="12" y="24" width="12" height="12" fill="#539bf5" fill-opacity="0.50"/>"##));
+        assert!(svg.contains(r##"<line x1="12" y1="24" x2="12" y2="48" stroke="#57ab5a" stroke-width="2"/>"##));
+        assert!(svg.contains(r#"class="caption">arrow</text>"#));
+        // The second frame starts below the legend of the first one
+        assert!(svg.contains(r#"<text x="0" y="92" class="caption">step 2</text>"#));
+
+        let html = render_html("Day <3>", &[picture]);
+        assert!(html.starts_with("<!DOCTYPE html>") && html.contains("<title>Day &lt;3&gt;</title>"));
+        assert_eq!(html.matches("<svg ").count(), 1);
+    }
+}
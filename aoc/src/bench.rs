@@ -0,0 +1,148 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default location of the benchmark history, relative to the workspace root.
+pub const HISTORY_PATH: &str = "bench-history.csv";
+
+const HISTORY_HEADER: &str = "timestamp,day,stage,runs,min_ns,median_ns,max_ns,input";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Statistics of the given timings, which must not be empty.
+    pub fn of(samples: &mut [Duration]) -> Self {
+        samples.sort();
+        let n = samples.len();
+        let median = if n % 2 == 1 { samples[n / 2] } else { (samples[n / 2 - 1] + samples[n / 2]) / 2 };
+        Self { min: samples[0], median, max: samples[n - 1] }
+    }
+}
+
+/// One benchmarked stage ("parse", "part1" or "part2") of one day, as stored in the history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub day: u32,
+    pub stage: String,
+    pub runs: u32,
+    pub stats: Stats,
+    /// Path of the input file, last on the line so that it may contain commas
+    pub input: String,
+}
+
+impl Entry {
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.timestamp, self.day, self.stage, self.runs,
+            self.stats.min.as_nanos(), self.stats.median.as_nanos(), self.stats.max.as_nanos(), self.input,
+        )
+    }
+
+    fn from_csv(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.splitn(8, ',').collect();
+        let [timestamp, day, stage, runs, min, median, max, input] = fields[..] else {
+            return None;
+        };
+        let nanos = |field: &str| field.parse().ok().map(Duration::from_nanos);
+        Some(Self {
+            timestamp: timestamp.parse().ok()?,
+            day: day.parse().ok()?,
+            stage: stage.to_string(),
+            runs: runs.parse().ok()?,
+            stats: Stats { min: nanos(min)?, median: nanos(median)?, max: nanos(max)? },
+            input: input.to_string(),
+        })
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Reads the history at `path`, which is empty if the file does not exist yet.
+pub fn load_history(path: &str) -> Result<Vec<Entry>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Cannot read benchmark history {path}: {e}")),
+    };
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| *line != HISTORY_HEADER && !line.is_empty())
+        .map(|(i, line)| Entry::from_csv(line).ok_or(format!("Invalid benchmark history {path}, line {}", i + 1)))
+        .collect()
+}
+
+pub fn append_history(path: &str, entries: &[Entry]) -> Result<(), String> {
+    let error = |e: io::Error| format!("Cannot write benchmark history {path}: {e}");
+    let mut file = OpenOptions::new().create(true).append(true).open(path).map_err(error)?;
+    if file.metadata().map_err(error)?.len() == 0 {
+        writeln!(file, "{HISTORY_HEADER}").map_err(error)?;
+    }
+    for entry in entries {
+        writeln!(file, "{}", entry.to_csv()).map_err(error)?;
+    }
+    Ok(())
+}
+
+/// The most recent entry for `stage` of `day` with the same input in `history`.
+pub fn previous<'a>(history: &'a [Entry], day: u32, stage: &str, input: &str) -> Option<&'a Entry> {
+    history.iter().rev().find(|entry| entry.day == day && entry.stage == stage && entry.input == input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: &[u64]) -> Vec<Duration> {
+        millis.iter().map(|m| Duration::from_millis(*m)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::of(&mut ms(&[5, 1, 3]));
+        assert_eq!((stats.min, stats.median, stats.max), (Duration::from_millis(1), Duration::from_millis(3), Duration::from_millis(5)));
+        assert_eq!(Stats::of(&mut ms(&[4, 1, 2, 8])).median, Duration::from_millis(3));
+        assert_eq!(Stats::of(&mut ms(&[7])).median, Duration::from_millis(7));
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let entry = Entry {
+            timestamp: 1702800000,
+            day: 16,
+            stage: String::from("part2"),
+            runs: 10,
+            stats: Stats::of(&mut ms(&[900, 1000, 1100])),
+            input: String::from("inputs/day16,v2.txt"),
+        };
+        assert_eq!(entry.to_csv(), "1702800000,16,part2,10,900000000,1000000000,1100000000,inputs/day16,v2.txt");
+        assert_eq!(Entry::from_csv(&entry.to_csv()), Some(entry));
+        assert_eq!(Entry::from_csv("1702800000,16,part2,10"), None);
+    }
+
+    #[test]
+    fn previous_entry() {
+        let entry = |timestamp, stage: &str, input: &str| Entry {
+            timestamp,
+            day: 6,
+            stage: stage.to_string(),
+            runs: 1,
+            stats: Stats::of(&mut ms(&[1])),
+            input: input.to_string(),
+        };
+        let history = [entry(1, "part1", "a.txt"), entry(2, "part1", "a.txt"), entry(3, "part1", "b.txt")];
+        assert_eq!(previous(&history, 6, "part1", "a.txt").map(|entry| entry.timestamp), Some(2));
+        assert_eq!(previous(&history, 6, "part1", "b.txt").map(|entry| entry.timestamp), Some(3));
+        assert_eq!(previous(&history, 6, "parse", "a.txt"), None);
+        assert_eq!(previous(&history, 7, "part1", "a.txt"), None);
+    }
+}
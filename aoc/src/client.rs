@@ -0,0 +1,268 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::submit::{parse_outcome, Outcome};
+
+/// The year of the puzzles solved here.
+pub const YEAR: u32 = 2023;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Variable to change the server, e.g. to a local stand-in for testing.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Variable holding the session token, the value of the `session` cookie of adventofcode.com.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Variable to change the cache directory, which defaults to `~/.cache/aoc`.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// Minimum time between two requests to the server, also across runs.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!("aoc/", env!("CARGO_PKG_VERSION"), " (Advent of Code 2023 solutions runner)");
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME").map(PathBuf::from)
+}
+
+/// File to read the session token from if `$AOC_SESSION` is not set: `~/.config/aoc/session`.
+pub fn session_file() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME").map(PathBuf::from).or_else(|| Some(home_dir()?.join(".config")))?;
+    Some(config_dir.join("aoc").join("session"))
+}
+
+fn session() -> Option<String> {
+    let session = env::var(SESSION_VAR).ok().or_else(|| fs::read_to_string(session_file()?).ok())?;
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+fn cache_dir() -> Result<PathBuf, String> {
+    if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+        return Ok(PathBuf::from(dir));
+    }
+    let cache_home = env::var_os("XDG_CACHE_HOME").map(PathBuf::from).or_else(|| Some(home_dir()?.join(".cache")));
+    cache_home.map(|dir| dir.join("aoc")).ok_or_else(|| format!("Cannot find a cache directory, please set {CACHE_DIR_VAR}"))
+}
+
+/// How long to wait before the next request, if the last one was sent at `last`.
+fn remaining_wait(last: Option<SystemTime>, now: SystemTime, min_interval: Duration) -> Duration {
+    match last.and_then(|last| now.duration_since(last).ok()) {
+        Some(elapsed) => min_interval.saturating_sub(elapsed),
+        None => Duration::ZERO,
+    }
+}
+
+/// Client for the puzzle server, which caches the inputs per year and sends at most
+/// one request per [`MIN_INTERVAL`].
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>, cache_dir: PathBuf, min_interval: Duration) -> Self {
+        let agent = ureq::AgentBuilder::new().user_agent(USER_AGENT).timeout(Duration::from_secs(30)).build();
+        Self { base_url: base_url.trim_end_matches('/').to_string(), session, cache_dir, min_interval, agent }
+    }
+
+    /// Client configured by the environment, see [`BASE_URL_VAR`], [`SESSION_VAR`], [`session_file`] and [`CACHE_DIR_VAR`].
+    pub fn from_env() -> Result<Self, String> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+        Ok(Self::new(&base_url, session(), cache_dir()?, MIN_INTERVAL))
+    }
+
+    pub fn cached_input_path(&self, day: u32) -> PathBuf {
+        self.cache_dir.join(YEAR.to_string()).join(format!("day{day:02}.txt"))
+    }
+
+    /// File of the wrong answers submitted so far, see [`Guesses`](crate::submit::Guesses).
+    pub fn guesses_path(&self) -> PathBuf {
+        self.cache_dir.join(YEAR.to_string()).join("guesses.toml")
+    }
+
+    fn last_request_path(&self) -> PathBuf {
+        self.cache_dir.join("last-request")
+    }
+
+    /// Sleeps until the server may be asked again, and notes the time of the coming request.
+    fn wait_for_turn(&self) -> Result<(), String> {
+        let path = self.last_request_path();
+        let last = fs::read_to_string(&path).ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        let wait = remaining_wait(last, SystemTime::now(), self.min_interval);
+        if !wait.is_zero() {
+            log::info!("Waiting {wait:.1?} before asking the server again");
+            thread::sleep(wait);
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        fs::create_dir_all(&self.cache_dir)
+            .and_then(|_| fs::write(&path, now.to_string()))
+            .map_err(|e| format!("Could not write {}: {e}", path.display()))
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or_else(|| {
+            let file = session_file().map_or_else(String::new, |file| format!(" or write it to {}", file.display()));
+            format!("No session token, please set {SESSION_VAR}{file}")
+        })
+    }
+
+    /// Sends `request` with the session cookie, with `form` as body if given, and returns the body of the response.
+    fn send(&self, request: ureq::Request, form: Option<&[(&str, &str)]>) -> Result<String, String> {
+        let url = request.url().to_string();
+        let request = request.set("Cookie", &format!("session={}", self.session()?));
+        self.wait_for_turn()?;
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => response.into_string().map_err(|e| format!("Could not read the response of {url}: {e}")),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(format!("{url} answered with status {status}: {}", body.lines().next().unwrap_or("").trim()))
+            }
+            Err(e) => Err(format!("Request to {url} failed: {e}")),
+        }
+    }
+
+    /// The personal input of `day`, from the cache if it was downloaded before.
+    /// Returns the input and whether it was downloaded now.
+    pub fn input(&self, day: u32) -> Result<(String, bool), String> {
+        let path = self.cached_input_path(day);
+        if let Ok(input) = fs::read_to_string(&path) {
+            return Ok((input, false));
+        }
+        let request = self.agent.get(&format!("{}/{YEAR}/day/{day}/input", self.base_url));
+        let input = self.send(request, None)?;
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, &input))
+            .map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+        Ok((input, true))
+    }
+
+    /// Submits `answer` for `part` of `day` and returns what the server made of it.
+    pub fn submit(&self, day: u32, part: u32, answer: &str) -> Result<Outcome, String> {
+        let request = self.agent.post(&format!("{}/{YEAR}/day/{day}/answer", self.base_url));
+        let level = part.to_string();
+        let page = self.send(request, Some(&[("level", &level), ("answer", answer)]))?;
+        Ok(parse_outcome(&page))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    use super::*;
+
+    /// Stand-in for the puzzle server, answering the given responses in order.
+    /// Returns its base URL and a handle to get the received requests, with their bodies.
+    pub(crate) fn serve(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+                let response = format!("HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len());
+                reader.into_inner().write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    pub(crate) fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_input_once() {
+        let (base_url, server) = serve(vec![(200, String::from("1abc2\n"))]);
+        let dir = temp_dir("fetch");
+        let client = Client::new(&base_url, Some(String::from("secret")), dir.clone(), Duration::ZERO);
+
+        assert_eq!(client.input(1), Ok((String::from("1abc2\n"), true)));
+        assert_eq!(client.input(1), Ok((String::from("1abc2\n"), false)));
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=secret\r\n"));
+        assert_eq!(fs::read_to_string(dir.join("2023").join("day01.txt")).unwrap(), "1abc2\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reports_server_errors() {
+        let (base_url, server) = serve(vec![(400, String::from("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"))]);
+        let dir = temp_dir("fetch-error");
+        let client = Client::new(&base_url, Some(String::from("expired")), dir.clone(), Duration::ZERO);
+
+        let error = client.input(3).unwrap_err();
+        assert!(error.contains("status 400: Puzzle inputs differ by user."), "{error}");
+        server.join().unwrap();
+        assert!(!client.cached_input_path(3).exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn needs_session() {
+        let client = Client::new("http://127.0.0.1:9", None, temp_dir("no-session"), Duration::ZERO);
+        assert!(client.input(1).unwrap_err().starts_with("No session token"));
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (base_url, server) = serve(vec![(200, String::from("<main><article><p>That's the right answer!</p></article></main>"))]);
+        let dir = temp_dir("submit");
+        let client = Client::new(&base_url, Some(String::from("secret")), dir.clone(), Duration::ZERO);
+
+        assert_eq!(client.submit(7, 2, "5905"), Ok(Outcome::Correct));
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2023/day/7/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=secret\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=5905"), "{}", requests[0]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rate_limit() {
+        let now = SystemTime::now();
+        let interval = Duration::from_secs(5);
+        assert_eq!(remaining_wait(None, now, interval), Duration::ZERO);
+        assert_eq!(remaining_wait(Some(now - Duration::from_secs(2)), now, interval), Duration::from_secs(3));
+        assert_eq!(remaining_wait(Some(now - Duration::from_secs(9)), now, interval), Duration::ZERO);
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+/// Default location of the ledger, relative to the workspace root.
+pub const LEDGER_PATH: &str = "answers.toml";
+
+const HEADER: &str = "\
+# Accepted answers per day and input file, checked by `aoc verify`.
+# Input files are relative to the input directory of the day, update with `aoc record`.
+
+";
+
+/// Accepted answers for one input file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn part(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+}
+
+/// The accepted answers of all days, stored as `[dayNN."<input file>"]` tables.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Ledger {
+    days: BTreeMap<String, BTreeMap<String, Answers>>,
+}
+
+fn day_key(day: u32) -> String {
+    format!("day{day:02}")
+}
+
+fn day_number(key: &str) -> Option<u32> {
+    key.strip_prefix("day").filter(|n| n.len() == 2).and_then(|n| n.parse().ok())
+}
+
+impl Ledger {
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        let ledger: Ledger = toml::from_str(text).map_err(|e| e.to_string())?;
+        if let Some(key) = ledger.days.keys().find(|key| day_number(key).is_none()) {
+            return Err(format!("Expected tables named dayNN, found \"{key}\""));
+        }
+        Ok(ledger)
+    }
+
+    pub fn to_toml(&self) -> String {
+        format!("{HEADER}{}", toml::to_string(self).unwrap())
+    }
+
+    /// Reads the ledger at `path`, which is empty if the file does not exist yet.
+    pub fn load(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::from_toml(&text).map_err(|e| format!("Invalid ledger {path}: {e}")),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Cannot read ledger {path}: {e}")),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_toml()).map_err(|e| format!("Cannot write ledger {path}: {e}"))
+    }
+
+    pub fn get(&self, day: u32, file: &str) -> Option<&Answers> {
+        self.days.get(&day_key(day)).and_then(|files| files.get(file))
+    }
+
+    pub fn record(&mut self, day: u32, file: &str, answers: Answers) {
+        self.days.entry(day_key(day)).or_default().insert(file.to_string(), answers);
+    }
+
+    /// All recorded answers as (day, input file, answers), ordered by day and file.
+    pub fn entries(&self) -> impl Iterator<Item = (u32, &str, &Answers)> {
+        self.days.iter().flat_map(|(key, files)| {
+            let day = day_number(key).unwrap();
+            files.iter().map(move |(file, answers)| (day, file.as_str(), answers))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(part1: &str, part2: Option<&str>) -> Answers {
+        Answers { part1: Some(part1.to_string()), part2: part2.map(String::from) }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut ledger = Ledger::default();
+        ledger.record(12, "input.txt", answers("7047", None));
+        ledger.record(1, "tests/example1.txt", answers("142", Some("142")));
+        ledger.record(1, "input.txt", answers("54697", Some("54885")));
+
+        let text = ledger.to_toml();
+        assert!(text.contains("[day01.\"input.txt\"]\npart1 = \"54697\"\npart2 = \"54885\"\n"));
+        assert!(text.ends_with("[day12.\"input.txt\"]\npart1 = \"7047\"\n"));
+        assert_eq!(Ledger::from_toml(&text).unwrap(), ledger);
+
+        let entries: Vec<(u32, &str)> = ledger.entries().map(|(day, file, _)| (day, file)).collect();
+        assert_eq!(entries, [(1, "input.txt"), (1, "tests/example1.txt"), (12, "input.txt")]);
+        assert_eq!(ledger.get(1, "input.txt").unwrap().part(2), Some("54885"));
+        assert_eq!(ledger.get(12, "input.txt").unwrap().part(2), None);
+    }
+
+    #[test]
+    fn rejects_unknown_tables() {
+        assert!(Ledger::from_toml("[day1.\"input.txt\"]\npart1 = \"1\"\n").is_err());
+        assert!(Ledger::from_toml("[answers.\"input.txt\"]\npart1 = \"1\"\n").is_err());
+    }
+}
@@ -0,0 +1,9 @@
+//! Runner and tooling around the solutions of all days.
+
+pub mod bench;
+pub mod client;
+pub mod ledger;
+pub mod registry;
+pub mod runner;
+pub mod serve;
+pub mod submit;
@@ -0,0 +1,598 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use aoc::bench::{self, append_history, load_history, Entry, Stats, HISTORY_PATH};
+use aoc::client::Client;
+use aoc::ledger::{Answers, Ledger, LEDGER_PATH};
+use aoc::registry;
+use aoc::runner;
+use aoc::serve;
+use aoc::submit::{Guesses, Hint, Outcome};
+use aoc_common::{day_input_path, init_config, init_logging, play_ansi, read_input, render_csv, render_explanations, render_explanations_json, render_html, render_json, render_picture, render_svg, start_recording, stop_recording, time, Day, Format, Parsed, Record, Rng, Status};
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Clone, Copy, Debug)]
+enum Selection {
+    All,
+    Day(u32),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+        s.parse().map(Selection::Day).map_err(|_| format!("expected a day number or \"all\", got \"{s}\""))
+    }
+}
+
+/// Output format of explanations, which have no CSV form unlike the answers.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum ExplainFormat {
+    Text,
+    Json,
+}
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print diagnostics to stderr: -v for info, -vv for debug, -vvv for trace.
+    /// Without it the level is taken from $AOC_LOG, defaulting to warn
+    #[arg(long, short, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Override a puzzle parameter of aoc.toml (or of $AOC_CONFIG), e.g. --set day02.red=20
+    #[arg(long = "set", global = true, value_name = "DAY.NAME=VALUE")]
+    overrides: Vec<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for one day or for all days
+    Run {
+        /// Day number, or "all"
+        day: Selection,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// Input file, or "-" for stdin. Defaults to dayNN/input.txt, inside $AOC_INPUT_DIR if set
+        #[arg(long, short)]
+        input: Option<String>,
+        /// Output format: text, json or csv
+        #[arg(long, default_value = "text")]
+        format: Format,
+        /// Number of days to run at the same time, defaults to the number of CPUs
+        #[arg(long, short, value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: Option<u32>,
+    },
+    /// Record the current answers for one day or for all days as accepted in answers.toml
+    Record {
+        /// Day number, or "all"
+        day: Selection,
+        /// Input file, relative to dayNN/ or to $AOC_INPUT_DIR/dayNN/ if set
+        #[arg(long, default_value = "input.txt")]
+        file: String,
+    },
+    /// Time parsing and both parts of one day or of all days over several runs
+    Bench {
+        /// Day number, or "all"
+        day: Selection,
+        /// Number of runs of every stage
+        #[arg(long, short = 'n', default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Input file, or "-" for stdin. Defaults to dayNN/input.txt, inside $AOC_INPUT_DIR if set
+        #[arg(long, short)]
+        input: Option<String>,
+    },
+    /// Check that all answers recorded in answers.toml are still computed, fails if any changed
+    Verify,
+    /// Download the personal input of one day into the cache and to dayNN/input.txt
+    /// (inside $AOC_INPUT_DIR if set). Needs the session token in $AOC_SESSION or ~/.config/aoc/session
+    Fetch {
+        day: u32,
+    },
+    /// Submit the answer of one part of a day. Answers that were rejected before, or that
+    /// contradict earlier "too high" or "too low" answers, are not sent
+    Submit {
+        day: u32,
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        /// Input file, or "-" for stdin. Defaults to dayNN/input.txt, inside $AOC_INPUT_DIR if set
+        #[arg(long, short)]
+        input: Option<String>,
+    },
+    /// Watch a part of day 10, 14 or 16 being solved, as an animation in the terminal or as an SVG file
+    Animate {
+        day: u32,
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        /// Input file, or "-" for stdin. Defaults to dayNN/input.txt, inside $AOC_INPUT_DIR if set
+        #[arg(long, short)]
+        input: Option<String>,
+        /// Frames per second
+        #[arg(long, default_value_t = 10.0)]
+        fps: f64,
+        /// Most frames to keep, spread evenly over the whole run
+        #[arg(long, default_value_t = 300, value_parser = clap::value_parser!(u64).range(2..))]
+        max_frames: u64,
+        /// Write an animated SVG to this file instead of playing in the terminal
+        #[arg(long)]
+        svg: Option<String>,
+    },
+    /// Draw the solved puzzle of day 3, 10, 11, 13 or 16 as a still SVG, or as a single HTML page
+    Picture {
+        day: u32,
+        /// Input file, or "-" for stdin. Defaults to dayNN/input.txt, inside $AOC_INPUT_DIR if set
+        #[arg(long, short)]
+        input: Option<String>,
+        /// File to write to, HTML if it ends in .html. Prints the SVG if not given
+        #[arg(long, short)]
+        out: Option<String>,
+    },
+    /// Explain line by line how day 1, 2, 4, 5, 7 or 15 reaches its answers
+    Explain {
+        day: u32,
+        /// Only explain this line of the input, counted from 1
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        line: Option<u64>,
+        /// Input file, or "-" for stdin. Defaults to dayNN/input.txt, inside $AOC_INPUT_DIR if set
+        #[arg(long, short)]
+        input: Option<String>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ExplainFormat::Text)]
+        format: ExplainFormat,
+    },
+    /// Serve a dashboard on localhost to run the days on their input files or on pasted inputs,
+    /// with the pictures and animations of the grid days
+    Serve {
+        #[arg(long, short, default_value_t = 8023)]
+        port: u16,
+    },
+    /// Print a random puzzle input for one day
+    Gen {
+        day: u32,
+        /// Seed of the random numbers, the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size of the input, e.g. the number of lines or the width of the map
+        #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+        size: u64,
+    },
+}
+
+fn days(selection: Selection) -> Result<Vec<&'static Day>, String> {
+    match selection {
+        Selection::All => Ok(registry::DAYS.iter().collect()),
+        Selection::Day(number) => Ok(vec![registry::get(number).ok_or(format!("Day {number} is not solved"))?]),
+    }
+}
+
+fn parse(day: &Day, path: &str, text: &str) -> Result<Box<dyn Parsed>, String> {
+    (day.parse)(text).map_err(|e| format!("Invalid input for day {} in {path}\n{}", day.number, e.diagnostic().trim_end()))
+}
+
+fn load(day: &Day, path: &str) -> Result<Box<dyn Parsed>, String> {
+    parse(day, path, &read_input(path, true).map_err(|e| e.to_string())?)
+}
+
+/// The selected days with their input files. When running all days, the input
+/// is `None` for days whose default input file does not exist.
+fn inputs(selection: Selection, input: Option<String>) -> Result<Vec<(&'static Day, Option<String>)>, String> {
+    let selection_is_all = matches!(selection, Selection::All);
+    if selection_is_all && input.is_some() {
+        return Err(String::from("--input can only be used for a single day"));
+    }
+    Ok(days(selection)?
+        .into_iter()
+        .map(|day| {
+            let path = match &input {
+                Some(path) => Some(path.clone()),
+                None => {
+                    let path = day_input_path(day.number, "input.txt");
+                    (path.exists() || !selection_is_all).then(|| path.to_string_lossy().into_owned())
+                }
+            };
+            (day, path)
+        })
+        .collect())
+}
+
+/// Table of the answers and timings, followed by a summary and the errors of failed days.
+fn print_text(records: &[Record]) {
+    println!("{:>3}  {:>4}  {:>20}  {:>12}", "Day", "Part", "Answer", "Time");
+    for (i, record) in records.iter().enumerate() {
+        let same_day_as_previous = i > 0 && records[i - 1].day == record.day;
+        match (record.status, &record.answer, record.elapsed) {
+            (Status::Solved, Some(answer), Some(elapsed)) => {
+                println!("{:>3}  {:>4}  {:>20}  {:>12.2?}", record.day, record.part, answer, elapsed)
+            }
+            (Status::MissingInput, _, _) if same_day_as_previous => {}
+            (Status::MissingInput, _, _) => println!("{:>3}  {:>4}  {:>20}", record.day, "-", "missing input"),
+            (Status::Failed, _, _) => println!("{:>3}  {:>4}  {:>20}", record.day, record.part, "FAILED"),
+            _ => println!("{:>3}  {:>4}  {:>20}", record.day, record.part, "not solved"),
+        }
+    }
+
+    let solved: Vec<&Record> = records.iter().filter(|record| record.status == Status::Solved).collect();
+    let total: Duration = solved.iter().filter_map(|record| record.elapsed).sum();
+    println!("\nSolved {} of {} parts in {total:.2?}", solved.len(), records.len());
+    for (i, record) in records.iter().enumerate() {
+        let Some(error) = &record.error else {
+            continue;
+        };
+        // Errors of the input are the same for all parts
+        let same_as_previous = i > 0 && records[i - 1].day == record.day && records[i - 1].error.as_ref() == Some(error);
+        let same_as_next = records.get(i + 1).is_some_and(|next| next.day == record.day && next.error.as_ref() == Some(error));
+        if same_as_previous {
+            continue;
+        }
+        let part = if same_as_next { String::new() } else { format!(" part {}", record.part) };
+        println!("Day {}{part} failed: {error}", record.day);
+    }
+}
+
+fn run(selection: Selection, part: Option<u32>, input: Option<String>, format: Format, jobs: Option<u32>) -> Result<(), String> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let threads = match jobs {
+        Some(jobs) => jobs as usize,
+        None => thread::available_parallelism().map_or(1, |threads| threads.get()),
+    };
+    let records = runner::run_days(&inputs(selection, input)?, &parts, threads);
+
+    match format {
+        Format::Text => print_text(&records),
+        Format::Json => print!("{}", render_json(&records)),
+        Format::Csv => print!("{}", render_csv(&records)),
+    }
+    let mut failed: Vec<u32> = records.iter().filter(|record| record.status == Status::Failed).map(|record| record.day).collect();
+    failed.dedup();
+    if !failed.is_empty() {
+        let days = failed.iter().map(|day| day.to_string()).collect::<Vec<_>>().join(", ");
+        return Err(format!("Failed {} {days}", if failed.len() == 1 { "day" } else { "days" }));
+    }
+    Ok(())
+}
+
+fn bench_stage<T, F: Fn() -> T>(runs: u32, f: F) -> Stats {
+    let mut samples: Vec<Duration> = (0..runs).map(|_| time(&f).1).collect();
+    Stats::of(&mut samples)
+}
+
+fn bench(selection: Selection, runs: u32, input: Option<String>) -> Result<(), String> {
+    let history = load_history(HISTORY_PATH)?;
+    let timestamp = bench::now();
+    let mut entries = Vec::new();
+
+    println!(
+        "{:>3}  {:>6}  {:>12}  {:>12}  {:>12}  {:>12}  {:>8}",
+        "Day", "Stage", "Min", "Median", "Max", "Previous", "Change"
+    );
+    for (day, path) in inputs(selection, input)? {
+        let Some(path) = path else {
+            println!("{:>3}  {:>6}  {:>12}", day.number, "-", "missing input");
+            continue;
+        };
+        let text = read_input(&path, true).map_err(|e| e.to_string())?;
+        let parsed = runner::catch_panic(|| parse(day, &path, &text)).and_then(|parsed| parsed);
+
+        // A panicking day is reported and skipped, like `aoc run` does
+        let measured = parsed.and_then(|parsed| runner::catch_panic(|| {
+            let mut stages = vec![("parse", bench_stage(runs, || (day.parse)(&text))), ("part1", bench_stage(runs, || parsed.part1()))];
+            let part2_solved = parsed.part2().is_some();
+            if part2_solved {
+                stages.push(("part2", bench_stage(runs, || parsed.part2())));
+            }
+            (stages, part2_solved)
+        }));
+        let (stages, part2_solved) = match measured {
+            Ok(measured) => measured,
+            Err(e) => {
+                println!("{:>3}  {:>6}  {:>12}", day.number, "-", "failed");
+                eprintln!("Day {}: {e}", day.number);
+                continue;
+            }
+        };
+        for (stage, stats) in stages {
+            let (previous, change) = match bench::previous(&history, day.number, stage, &path) {
+                Some(entry) => {
+                    let before = entry.stats.median.as_secs_f64();
+                    // Medians too short for the clock give no meaningful change
+                    let change = if before > 0.0 {
+                        format!("{:+.1}%", (stats.median.as_secs_f64() - before) / before * 100.0)
+                    } else {
+                        String::from("-")
+                    };
+                    (format!("{:.2?}", entry.stats.median), change)
+                }
+                None => (String::from("-"), String::new()),
+            };
+            println!(
+                "{:>3}  {:>6}  {:>12.2?}  {:>12.2?}  {:>12.2?}  {:>12}  {:>8}",
+                day.number, stage, stats.min, stats.median, stats.max, previous, change
+            );
+            entries.push(Entry { timestamp, day: day.number, stage: stage.to_string(), runs, stats, input: path.clone() });
+        }
+        if !part2_solved {
+            println!("{:>3}  {:>6}  {:>12}", day.number, "part2", "not solved");
+        }
+    }
+    append_history(HISTORY_PATH, &entries)
+}
+
+fn record(selection: Selection, file: &str) -> Result<(), String> {
+    let mut ledger = Ledger::load(LEDGER_PATH)?;
+
+    println!("{:>3}  {:>4}  {:>20}", "Day", "Part", "Answer");
+    for day in days(selection)? {
+        let path = ledger_input_path(day.number, file);
+        if !path.exists() && matches!(selection, Selection::All) {
+            println!("{:>3}  {:>4}  {:>20}", day.number, "-", "missing input");
+            continue;
+        }
+        let parsed = load(day, &path.to_string_lossy())?;
+        let answers = Answers { part1: Some(parsed.part1()), part2: parsed.part2() };
+        for part in 1..=2 {
+            if let Some(answer) = answers.part(part) {
+                println!("{:>3}  {:>4}  {:>20}", day.number, part, answer);
+            }
+        }
+        ledger.record(day.number, file, answers);
+    }
+    ledger.save(LEDGER_PATH)
+}
+
+/// Path of the input `file` of `day` recorded in the ledger. Inputs missing from `$AOC_INPUT_DIR`
+/// are looked up in `dayNN/` of the workspace, where the committed examples are.
+fn ledger_input_path(day: u32, file: &str) -> PathBuf {
+    let path = day_input_path(day, file);
+    let workspace_path = Path::new(&format!("day{day:02}")).join(file);
+    if !path.exists() && workspace_path.exists() {
+        return workspace_path;
+    }
+    path
+}
+
+fn verify() -> Result<(), String> {
+    let ledger = Ledger::load(LEDGER_PATH)?;
+    let mut n_checked = 0;
+    let mut n_changed = 0;
+    let mut n_missing = 0;
+    // Examples are committed, personal inputs only exist where they were fetched
+    let mut n_personal = 0;
+
+    println!("{:>3}  {:>4}  {:<24}  {:>20}  {:>20}  Status", "Day", "Part", "Input", "Expected", "Actual");
+    for (number, file, expected) in ledger.entries() {
+        let path = ledger_input_path(number, file);
+        let parsed = match registry::get(number) {
+            None => Err(String::from("not solved")),
+            Some(_) if !path.exists() => {
+                println!("{:>3}  {:>4}  {:<24}  {:>20}  {:>20}  missing input", number, "-", file, "", "");
+                n_missing += 1;
+                continue;
+            }
+            Some(day) => runner::catch_panic(|| load(day, &path.to_string_lossy())).and_then(|parsed| parsed).map_err(|e| {
+                eprintln!("{e}");
+                String::from("invalid input")
+            }),
+        };
+        for part in 1..=2 {
+            let Some(expected) = expected.part(part) else {
+                continue;
+            };
+            let actual = match &parsed {
+                Ok(parsed) if part == 1 => runner::catch_panic(|| parsed.part1()),
+                Ok(parsed) => runner::catch_panic(|| parsed.part2().unwrap_or_else(|| String::from("not solved"))),
+                Err(e) => Ok(e.clone()),
+            };
+            let actual = actual.unwrap_or_else(|e| {
+                eprintln!("Day {number} part {part} on {file}: {e}");
+                String::from("panicked")
+            });
+            let status = if actual == expected { "ok" } else { "CHANGED" };
+            println!("{:>3}  {:>4}  {:<24}  {:>20}  {:>20}  {}", number, part, file, expected, actual, status);
+            n_checked += 1;
+            if actual != expected {
+                n_changed += 1;
+            }
+            if !file.starts_with("tests/") {
+                n_personal += 1;
+            }
+        }
+    }
+
+    let missing = match n_missing {
+        0 => String::new(),
+        1 => String::from(", 1 input is missing"),
+        n => format!(", {n} inputs are missing"),
+    };
+    if n_changed > 0 {
+        return Err(format!("{n_changed} of {n_checked} answers differ from {LEDGER_PATH}{missing}"));
+    }
+    if n_checked == 0 {
+        return Err(format!("No answers of {LEDGER_PATH} could be checked{missing}"));
+    }
+    println!("All {n_checked} checked answers match {LEDGER_PATH}, {n_personal} of them for personal inputs{missing}");
+    if n_personal == 0 {
+        println!("Record the answers for the personal inputs with `aoc record all` to check them too");
+    }
+    Ok(())
+}
+
+fn fetch(day: u32) -> Result<(), String> {
+    let client = Client::from_env()?;
+    let (input, downloaded) = client.input(day)?;
+    let cached = client.cached_input_path(day);
+    println!("{} {}", if downloaded { "Downloaded" } else { "Already cached:" }, cached.display());
+
+    let path = day_input_path(day, "input.txt");
+    match fs::read_to_string(&path) {
+        Ok(existing) if existing == input => {}
+        Ok(_) => log::warn!("Keeping {}, which differs from the downloaded input", path.display()),
+        Err(_) => {
+            fs::create_dir_all(path.parent().unwrap())
+                .and_then(|_| fs::write(&path, &input))
+                .map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+            println!("Wrote {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+fn submit(number: u32, part: u32, input: Option<String>) -> Result<(), String> {
+    let day = registry::get(number).ok_or(format!("Day {number} is not solved"))?;
+    let path = input.clone().unwrap_or_else(|| day_input_path(number, "input.txt").to_string_lossy().into_owned());
+    let parsed = load(day, &path)?;
+    let answer = if part == 1 { Some(parsed.part1()) } else { parsed.part2() }
+        .ok_or(format!("Part {part} of day {number} is not solved"))?;
+
+    let client = Client::from_env()?;
+    let guesses_path = client.guesses_path();
+    let mut guesses = Guesses::load(&guesses_path)?;
+    guesses.check(number, part, &answer, bench::now())?;
+    println!("Submitting {answer} for day {number} part {part}");
+    match client.submit(number, part, &answer)? {
+        Outcome::Correct => {
+            println!("That's the right answer");
+            // Only the default input is the one the answer was accepted for
+            if input.is_none() {
+                let mut ledger = Ledger::load(LEDGER_PATH)?;
+                let mut answers = ledger.get(number, "input.txt").cloned().unwrap_or_default();
+                match part {
+                    1 => answers.part1 = Some(answer),
+                    _ => answers.part2 = Some(answer),
+                }
+                ledger.record(number, "input.txt", answers);
+                ledger.save(LEDGER_PATH)?;
+                println!("Recorded in {LEDGER_PATH}");
+            }
+        }
+        Outcome::Wrong(hint, wait) => {
+            let hint_text = match hint {
+                Hint::TooHigh => ", too high",
+                Hint::TooLow => ", too low",
+                Hint::Neither => "",
+            };
+            println!("That's not the right answer{hint_text}. Wait {}s before trying again", wait.as_secs());
+            guesses.record(number, part, &answer, hint);
+            guesses.block_until(bench::now() + wait.as_secs());
+        }
+        Outcome::Wait(wait) => {
+            println!("An answer was submitted too recently, wait {}s before trying again", wait.as_secs());
+            guesses.block_until(bench::now() + wait.as_secs());
+        }
+        Outcome::WrongLevel => println!("Part {part} of day {number} is either solved already or not unlocked yet"),
+        Outcome::Unknown(text) => return Err(format!("Unexpected response of the server: {text}")),
+    }
+    guesses.save(&guesses_path)
+}
+
+fn animate(number: u32, part: u32, input: Option<String>, fps: f64, max_frames: u64, svg: Option<String>) -> Result<(), String> {
+    if !fps.is_finite() || fps <= 0.0 {
+        return Err(format!("Expected a positive number of frames per second, got {fps}"));
+    }
+    let day = registry::get(number).ok_or(format!("Day {number} is not solved"))?;
+    let path = input.unwrap_or_else(|| day_input_path(number, "input.txt").to_string_lossy().into_owned());
+    let parsed = load(day, &path)?;
+
+    start_recording(max_frames as usize);
+    let answer = runner::catch_panic(|| if part == 1 { Some(parsed.part1()) } else { parsed.part2() });
+    let frames = stop_recording();
+    let answer = answer?.ok_or(format!("Part {part} of day {number} is not solved"))?;
+    if frames.is_empty() {
+        return Err(format!("Part {part} of day {number} records no frames, only days 10, 14 and 16 do"));
+    }
+
+    match svg {
+        Some(svg) => {
+            fs::write(&svg, render_svg(&frames, fps)).map_err(|e| format!("Could not write {svg}: {e}"))?;
+            println!("Wrote {} frames to {svg}", frames.len());
+        }
+        None => play_ansi(&frames, fps, &mut io::stdout().lock()).map_err(|e| e.to_string())?,
+    }
+    println!("Day {number} part {part}: {answer}");
+    Ok(())
+}
+
+fn picture(number: u32, input: Option<String>, out: Option<String>) -> Result<(), String> {
+    let day = registry::get(number).ok_or(format!("Day {number} is not solved"))?;
+    let path = input.unwrap_or_else(|| day_input_path(number, "input.txt").to_string_lossy().into_owned());
+    let parsed = load(day, &path)?;
+    let frames = runner::catch_panic(|| parsed.pictures())?;
+    if frames.is_empty() {
+        return Err(format!("Day {number} draws no pictures, only days 3, 10, 11, 13 and 16 do"));
+    }
+
+    match out {
+        Some(out) => {
+            let text = if out.ends_with(".html") { render_html(&format!("Day {number}"), &frames) } else { render_picture(&frames) };
+            fs::write(&out, text).map_err(|e| format!("Could not write {out}: {e}"))?;
+            println!("Wrote {out}");
+        }
+        None => print!("{}", render_picture(&frames)),
+    }
+    Ok(())
+}
+
+fn explain(number: u32, line: Option<u64>, input: Option<String>, format: ExplainFormat) -> Result<(), String> {
+    let day = registry::get(number).ok_or(format!("Day {number} is not solved"))?;
+    let path = input.unwrap_or_else(|| day_input_path(number, "input.txt").to_string_lossy().into_owned());
+    let parsed = load(day, &path)?;
+    let mut explanations = runner::catch_panic(|| parsed.explain())?;
+    if explanations.is_empty() {
+        return Err(format!("Day {number} explains nothing, only days 1, 2, 4, 5, 7 and 15 do"));
+    }
+    if let Some(line) = line {
+        explanations.retain(|explanation| explanation.line as u64 == line);
+        if explanations.is_empty() {
+            return Err(format!("Day {number} explains nothing on line {line} of {path}"));
+        }
+    }
+
+    match format {
+        ExplainFormat::Text => print!("{}", render_explanations(&explanations)),
+        ExplainFormat::Json => print!("{}", render_explanations_json(&explanations)),
+    }
+    Ok(())
+}
+
+fn gen(number: u32, seed: u64, size: u64) -> Result<(), String> {
+    let day = registry::get(number).ok_or(format!("Day {number} is not solved"))?;
+    let input = (day.generate)(&mut Rng::new(seed), size as usize).ok_or(format!("Day {number} has no input generator"))?;
+    print!("{input}");
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    if let Err(e) = init_logging(cli.verbose).and_then(|_| init_config(&cli.overrides, registry::validate_config)) {
+        eprintln!("{e}");
+        std::process::exit(2);
+    }
+    let result = match cli.command {
+        Command::Run { day, part, input, format, jobs } => run(day, part, input, format, jobs),
+        Command::Bench { day, runs, input } => bench(day, runs, input),
+        Command::Record { day, file } => record(day, &file),
+        Command::Verify => verify(),
+        Command::Fetch { day } => fetch(day),
+        Command::Submit { day, part, input } => submit(day, part, input),
+        Command::Animate { day, part, input, fps, max_frames, svg } => animate(day, part, input, fps, max_frames, svg),
+        Command::Picture { day, input, out } => picture(day, input, out),
+        Command::Explain { day, line, input, format } => explain(day, line, input, format),
+        Command::Serve { port } => serve::serve(port),
+        Command::Gen { day, seed, size } => gen(day, seed, size),
+    };
+    if let Err(e) = result {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::{Config, Day};
+
+/// Every solved day, in order.
+pub static DAYS: [Day; 15] = [
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+    Day::of::<day14::Day14>(),
+    Day::of::<day15::Day15>(),
+    Day::of::<day16::Day16>(),
+];
+
+pub fn get(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Checks the parameters of the config against the ones the days read, see [`Config::validate`].
+/// Parameters of days that are not solved are errors too.
+pub fn validate_config(config: &Config) -> Result<(), String> {
+    for number in config.days() {
+        let day = get(number).ok_or_else(|| format!("Unknown day{number:02} in the config, day {number} is not solved"))?;
+        config.validate(number, day.params)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+use std::thread;
+
+use aoc_common::{input_hash, read_input, time, Day, Record};
+
+thread_local! {
+    /// Where the last panic within [`catch_panic`] on this thread happened, noted by the panic hook.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// Whether this thread is within [`catch_panic`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Installs the panic hook, once for the whole process. The messages of panics within [`catch_panic`]
+/// end up in its errors, so the hook notes their location and only logs them at debug level
+/// instead of printing them. Other panics go to the previous hook.
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                PANIC_LOCATION.with(|location| *location.borrow_mut() = info.location().map(|location| location.to_string()));
+                log::debug!("{info}");
+            } else {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => String::from("panicked"),
+    }
+}
+
+/// Calls `f`, turning a panic into an error with its message and location.
+pub fn catch_panic<T, F: FnOnce() -> T>(f: F) -> Result<T, String> {
+    install_hook();
+    PANIC_LOCATION.with(|location| location.borrow_mut().take());
+    let catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(catching);
+    result.map_err(|payload| {
+        let message = panic_message(&*payload);
+        match PANIC_LOCATION.with(|location| location.borrow_mut().take()) {
+            Some(location) => format!("{message} (at {location})"),
+            None => message,
+        }
+    })
+}
+
+/// Runs `parts` of `day` on the input at `path`, `None` if it is missing. An input that
+/// cannot be read or parsed fails all parts, a panic fails the part it happened in.
+pub fn run_day(day: &Day, path: Option<&str>, parts: &[u32]) -> Vec<Record> {
+    let Some(path) = path else {
+        return parts.iter().map(|part| Record::missing_input(day.number, *part)).collect();
+    };
+    match read_input(path, true) {
+        Ok(text) => solve(day, &text, path, parts),
+        Err(e) => parts.iter().map(|part| Record::failed(day.number, *part, &e.to_string(), None)).collect(),
+    }
+}
+
+/// Runs `parts` of `day` on the input `text`, read from `source`, like [`run_day`].
+pub fn solve(day: &Day, text: &str, source: &str, parts: &[u32]) -> Vec<Record> {
+    let fail_all = |error: &str, hash: Option<&str>| {
+        parts.iter().map(|part| Record::failed(day.number, *part, error, hash)).collect()
+    };
+    let hash = input_hash(text);
+    let parsed = match catch_panic(|| (day.parse)(text)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => return fail_all(&format!("Invalid input in {source}\n{}", e.diagnostic().trim_end()), Some(&hash)),
+        Err(message) => return fail_all(&message, Some(&hash)),
+    };
+    parts.iter()
+        .map(|part| match catch_panic(|| time(|| if *part == 1 { Some(parsed.part1()) } else { parsed.part2() })) {
+            Ok((Some(answer), elapsed)) => Record::solved(day.number, *part, answer, elapsed, &hash),
+            Ok((None, _)) => Record::not_solved(day.number, *part, &hash),
+            Err(message) => Record::failed(day.number, *part, &message, Some(&hash)),
+        })
+        .collect()
+}
+
+/// Runs the days with their input files on up to `threads` threads, see [`run_day`].
+/// Returns the records in the order of `jobs`.
+pub fn run_days(jobs: &[(&Day, Option<String>)], parts: &[u32], threads: usize) -> Vec<Record> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![Vec::new(); jobs.len()]);
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some((day, path)) = jobs.get(i) else {
+                    break;
+                };
+                let records = run_day(day, path.as_deref(), parts);
+                results.lock().unwrap()[i] = records;
+            });
+        }
+    });
+    results.into_inner().unwrap().into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fmt::Display;
+    use std::fs;
+
+    use aoc_common::{ParseError, Solution, Status};
+
+    use super::*;
+
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u32 = 99;
+
+        type Input = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            if input.trim().is_empty() {
+                return Err(ParseError::new("Empty input"));
+            }
+            Ok(input.trim().to_string())
+        }
+
+        fn part1(input: &Self::Input) -> impl Display {
+            input.len()
+        }
+
+        fn part2(input: &Self::Input) -> Option<impl Display> {
+            assert!(input != "panic", "Could not find start marker!");
+            Some(input.to_uppercase())
+        }
+    }
+
+    static FRAGILE: Day = Day::of::<Fragile>();
+
+    #[test]
+    fn isolates_failures() {
+        let dir = env::temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = |name: &str, text: &str| {
+            let path = dir.join(name);
+            fs::write(&path, text).unwrap();
+            Some(path.to_string_lossy().into_owned())
+        };
+        let jobs = [
+            (&FRAGILE, input("ok.txt", "abc\n")),
+            (&FRAGILE, input("panic.txt", "panic\n")),
+            (&FRAGILE, input("empty.txt", "\n")),
+            (&FRAGILE, Some(dir.join("missing.txt").to_string_lossy().into_owned())),
+            (&FRAGILE, None),
+        ];
+
+        let records = run_days(&jobs, &[1, 2], 3);
+        let statuses: Vec<Status> = records.iter().map(|record| record.status).collect();
+        assert_eq!(statuses, [
+            Status::Solved, Status::Solved,
+            Status::Solved, Status::Failed,
+            Status::Failed, Status::Failed,
+            Status::Failed, Status::Failed,
+            Status::MissingInput, Status::MissingInput,
+        ]);
+        assert_eq!(records[1].answer.as_deref(), Some("ABC"));
+        let error = records[3].error.as_deref().unwrap();
+        assert!(error.starts_with("Could not find start marker! (at aoc/src/runner.rs:"), "{error}");
+        assert!(records[4].error.as_deref().unwrap().contains("Empty input"));
+        assert!(records[6].error.as_deref().unwrap().contains("missing.txt"));
+        assert_eq!(records[6].input_hash, None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn catches_panics() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        let error = catch_panic(|| panic!("{} failed", 3)).unwrap_err();
+        assert!(error.starts_with("3 failed (at aoc/src/runner.rs:"), "{error}");
+        // Also when nested
+        let nested = catch_panic(|| catch_panic(|| panic!("inner")).unwrap_err());
+        assert!(nested.unwrap().starts_with("inner (at aoc/src/runner.rs:"));
+    }
+}
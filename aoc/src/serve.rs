@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Read;
+
+use aoc_common::{day_input_path, normalize_input, render_picture, render_svg, start_recording, stop_recording, xml_escape, Day, Frame, Record, Status};
+use tiny_http::{Header, Response, Server};
+
+use crate::ledger::{Ledger, LEDGER_PATH};
+use crate::registry;
+use crate::runner::{self, catch_panic};
+
+/// Most frames of the animations shown on the day pages.
+const MAX_FRAMES: usize = 200;
+
+/// Frames per second of the animations shown on the day pages.
+const FPS: f64 = 10.0;
+
+/// Largest accepted request body, puzzle inputs are far smaller.
+const MAX_BODY: u64 = 10 << 20;
+
+const STYLE: &str = "\
+body { background: #1c2128; color: #e6edf3; font-family: sans-serif; margin: 2em; }
+a { color: #539bf5; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { border: 1px solid #444c56; padding: 0.3em 0.8em; text-align: left; }
+textarea { width: 100%; height: 12em; font-family: monospace; background: #22272e; color: #e6edf3; }
+pre.error { color: #e5534b; white-space: pre-wrap; }
+svg { display: block; margin: 1em 0; }
+";
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        xml_escape(title)
+    )
+}
+
+/// The names of the `.txt` files in the input directory of the day, sorted.
+fn input_files(day: u32) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(day_input_path(day, ""))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.ends_with(".txt"))
+        .collect();
+    files.sort();
+    files
+}
+
+fn index() -> String {
+    let ledger = Ledger::load(LEDGER_PATH).unwrap_or_default();
+    let mut body = String::from("<h1>Advent of Code 2023</h1>\n<table>\n<tr><th>Day</th><th>Inputs</th><th>Part 1</th><th>Part 2</th></tr>\n");
+    for day in &registry::DAYS {
+        let answers = ledger.get(day.number, "input.txt");
+        let answer = |part| answers.and_then(|answers| answers.part(part)).map_or_else(String::new, xml_escape);
+        writeln!(
+            body,
+            "<tr><td><a href=\"/day/{0}\">Day {0}</a></td><td>{1}</td><td>{2}</td><td>{3}</td></tr>",
+            day.number,
+            input_files(day.number).len(),
+            answer(1),
+            answer(2)
+        ).unwrap();
+    }
+    body.push_str("</table>\n<p>The answers are the accepted ones for input.txt from answers.toml.</p>\n");
+    page("Advent of Code 2023", &body)
+}
+
+/// The form to choose or paste an input and the parts to run.
+fn form(day: u32, form: &HashMap<String, String>) -> String {
+    let selected = form.get("source").map_or("input.txt", String::as_str);
+    let mut options = String::new();
+    for file in input_files(day) {
+        let is_selected = if file == selected { " selected" } else { "" };
+        writeln!(options, "<option value=\"{0}\"{is_selected}>{0}</option>", xml_escape(&file)).unwrap();
+    }
+    let is_pasted = if selected.is_empty() { " selected" } else { "" };
+    format!(
+        "<form method=\"post\" action=\"/day/{day}\">
+<p>Input <select name=\"source\">\n{options}<option value=\"\"{is_pasted}>the text below</option>\n</select>
+or upload <input type=\"file\" onchange=\"this.files[0].text().then(text => {{ this.form.text.value = text; this.form.source.value = ''; }})\"></p>
+<textarea name=\"text\" placeholder=\"Paste an input here\">{}</textarea>
+<p><button name=\"part\" value=\"1\">Run part 1</button> <button name=\"part\" value=\"2\">Run part 2</button> <button name=\"part\" value=\"both\">Run both parts</button></p>
+</form>\n",
+        xml_escape(form.get("text").map_or("", String::as_str))
+    )
+}
+
+fn results(records: &[Record]) -> String {
+    let mut res = String::from("<h2>Answers</h2>\n<table>\n<tr><th>Part</th><th>Answer</th><th>Time</th></tr>\n");
+    for record in records {
+        let (answer, time) = match (record.status, &record.answer, record.elapsed) {
+            (Status::Solved, Some(answer), Some(elapsed)) => (xml_escape(answer), format!("{elapsed:.2?}")),
+            (Status::Failed, _, _) => (String::from("FAILED"), String::new()),
+            _ => (String::from("not solved"), String::new()),
+        };
+        writeln!(res, "<tr><td>{}</td><td>{answer}</td><td>{time}</td></tr>", record.part).unwrap();
+    }
+    res.push_str("</table>\n");
+    let mut errors: Vec<&str> = records.iter().filter_map(|record| record.error.as_deref()).collect();
+    errors.dedup();
+    for error in errors {
+        writeln!(res, "<pre class=\"error\">{}</pre>", xml_escape(error)).unwrap();
+    }
+    res
+}
+
+/// Runs the chosen parts on the chosen input and shows the answers, with the
+/// pictures of the solved puzzle and the animation of the run if the day draws them.
+fn run(day: &Day, form: &HashMap<String, String>) -> Result<String, String> {
+    let parts = match form.get("part").map(String::as_str) {
+        Some("1") => vec![1],
+        Some("2") => vec![2],
+        _ => vec![1, 2],
+    };
+    let (source, text) = match form.get("source").map(String::as_str) {
+        None | Some("") => (String::from("the pasted text"), form.get("text").cloned().unwrap_or_default()),
+        // Only the listed files, so that no other file can be read
+        Some(file) if input_files(day.number).iter().any(|listed| listed == file) => {
+            let path = day_input_path(day.number, file);
+            let text = fs::read_to_string(&path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+            (path.to_string_lossy().into_owned(), text)
+        }
+        Some(file) => return Err(format!("There is no input file {file} for day {}", day.number)),
+    };
+
+    // Like the input files read by the other commands, browsers send CRLF line endings
+    let text = normalize_input(&text, true);
+    start_recording(MAX_FRAMES);
+    let records = runner::solve(day, &text, &source, &parts);
+    let frames = stop_recording();
+
+    let mut res = results(&records);
+    if records.iter().all(|record| record.status != Status::Failed) {
+        let pictures: Vec<Frame> = catch_panic(|| (day.parse)(&text).map(|parsed| parsed.pictures()))
+            .ok()
+            .and_then(Result::ok)
+            .unwrap_or_default();
+        if !pictures.is_empty() {
+            writeln!(res, "<h2>Picture</h2>\n{}", render_picture(&pictures)).unwrap();
+        }
+        if !frames.is_empty() {
+            writeln!(res, "<h2>Animation</h2>\n{}", render_svg(&frames, FPS)).unwrap();
+        }
+    }
+    Ok(res)
+}
+
+fn day_page(day: &Day, method: &str, body: &str) -> String {
+    let fields: HashMap<String, String> = form_urlencoded::parse(body.as_bytes()).into_owned().collect();
+    let mut html = format!("<p><a href=\"/\">All days</a></p>\n<h1>Day {}</h1>\n{}", day.number, form(day.number, &fields));
+    if method == "POST" {
+        match run(day, &fields) {
+            Ok(results) => html.push_str(&results),
+            Err(e) => writeln!(html, "<pre class=\"error\">{}</pre>", xml_escape(&e)).unwrap(),
+        }
+    }
+    page(&format!("Day {}", day.number), &html)
+}
+
+/// The status code and HTML page answering a request for `url`, with the form data in `body`.
+pub fn respond(method: &str, url: &str, body: &str) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or_default();
+    let day = path.strip_prefix("/day/").and_then(|number| number.parse().ok()).and_then(registry::get);
+    match (method, path, day) {
+        ("GET", "/", _) => (200, index()),
+        ("GET" | "POST", _, Some(day)) => (200, day_page(day, method, body)),
+        _ => (404, page("Not found", "<h1>Not found</h1>\n<p><a href=\"/\">All days</a></p>\n")),
+    }
+}
+
+/// Serves the dashboard on localhost at `port` until the process is stopped.
+/// Requests are handled one after another, with the inputs read relative to the current directory.
+pub fn serve(port: u16) -> Result<(), String> {
+    let server = Server::http(("127.0.0.1", port)).map_err(|e| format!("Could not listen on port {port}: {e}"))?;
+    println!("Serving on http://127.0.0.1:{port}/, stop with Ctrl-C");
+    let content_type = Header::from_bytes("Content-Type", "text/html; charset=utf-8").unwrap();
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let (status, html) = match request.as_reader().take(MAX_BODY).read_to_string(&mut body) {
+            Ok(_) => respond(request.method().as_str(), request.url(), &body),
+            Err(e) => (400, page("Bad request", &format!("<pre class=\"error\">{}</pre>\n", xml_escape(&e.to_string())))),
+        };
+        log::info!("{} {} {status}", request.method(), request.url());
+        let response = Response::from_string(html).with_status_code(status).with_header(content_type.clone());
+        if let Err(e) = request.respond(response) {
+            log::warn!("Could not respond: {e}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_days() {
+        let (status, html) = respond("GET", "/", "");
+        assert_eq!(status, 200);
+        assert!(html.contains("<a href=\"/day/1\">Day 1</a>") && html.contains("<a href=\"/day/16\">Day 16</a>"));
+        assert_eq!(respond("GET", "/day/8", "").0, 404);
+        assert_eq!(respond("GET", "/favicon.ico", "").0, 404);
+    }
+
+    #[test]
+    fn runs_pasted_input() {
+        let (status, html) = respond("POST", "/day/1", "source=&part=1&text=1abc2%0Apqr3stu8vwx%0Aa1b2c3d4e5f%0Atreb7uchet%0A");
+        assert_eq!(status, 200);
+        assert!(html.contains("<tr><td>1</td><td>142</td>"), "{html}");
+        assert!(!html.contains("<tr><td>2</td>"));
+        // The pasted input is kept in the form
+        assert!(html.contains("treb7uchet\n</textarea>"));
+
+        // As sent by browsers, with CRLF line endings and a blank line at the end
+        let pattern = "%23.%23%23..%23%23.%0D%0A..%23.%23%23.%23.%0D%0A%23%23......%23%0D%0A%23%23......%23%0D%0A..%23.%23%23.%23.%0D%0A..%23%23..%23%23.%0D%0A%23.%23.%23%23.%23.%0D%0A%0D%0A";
+        let (_, html) = respond("POST", "/day/13", &format!("source=&part=1&text={pattern}"));
+        assert!(html.contains("<tr><td>1</td><td>5</td>"), "{html}");
+    }
+
+    #[test]
+    fn shows_pictures_and_errors() {
+        let pattern = "%23.%23%23..%23%23.%0A..%23.%23%23.%23.%0A%23%23......%23%0A%23%23......%23%0A..%23.%23%23.%23.%0A..%23%23..%23%23.%0A%23.%23.%23%23.%23.%0A";
+        let (_, html) = respond("POST", "/day/13", &format!("source=&part=both&text={pattern}"));
+        assert!(html.contains("<tr><td>1</td><td>5</td>") && html.contains("<h2>Picture</h2>\n<svg"), "{html}");
+
+        let (_, html) = respond("POST", "/day/13", "source=&text=%23x%0A");
+        assert!(html.contains("FAILED") && html.contains("<pre class=\"error\">Invalid input in the pasted text"), "{html}");
+        assert!(!html.contains("<svg"));
+
+        let (_, html) = respond("POST", "/day/13", "source=..%2FCargo.toml");
+        assert!(html.contains("There is no input file ../Cargo.toml for day 13"));
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// How a wrong answer compares to the right one, as far as the server tells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    Neither,
+}
+
+/// The response of the server to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// The server blocks further answers for the given time after a wrong one
+    Wrong(Hint, Duration),
+    /// The previous answer was submitted too recently, retry after the given time
+    Wait(Duration),
+    /// The part is either solved already or not unlocked yet
+    WrongLevel,
+    /// A response that could not be understood, with its text
+    Unknown(String),
+}
+
+/// The text of the `<main>` element of `html`, without tags and with collapsed whitespace.
+fn main_text(html: &str) -> String {
+    let main = html.split_once("<main>").map_or(html, |(_, main)| main);
+    let main = main.split_once("</main>").map_or(main, |(main, _)| main);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in main.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses durations like "1m 5s" or "39s".
+fn parse_duration(text: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    for part in text.split_whitespace() {
+        let (number, factor) = match part.strip_suffix('s') {
+            Some(number) => (number, 1),
+            None => (part.strip_suffix('m')?, 60),
+        };
+        seconds += number.parse::<u64>().ok()? * factor;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// The time to wait after a wrong answer, from "please wait one minute" or "please wait 5 minutes".
+fn parse_wrong_answer_wait(text: &str) -> Duration {
+    let minutes = text.split_once("please wait ")
+        .and_then(|(_, rest)| rest.split_whitespace().next())
+        .and_then(|number| if number == "one" { Some(1) } else { number.parse().ok() })
+        .unwrap_or(1);
+    Duration::from_secs(60 * minutes)
+}
+
+pub fn parse_outcome(html: &str) -> Outcome {
+    let text = main_text(html);
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text.split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_duration(wait));
+        Outcome::Wait(wait.unwrap_or(Duration::from_secs(60)))
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("your answer is too high") {
+            Hint::TooHigh
+        } else if text.contains("your answer is too low") {
+            Hint::TooLow
+        } else {
+            Hint::Neither
+        };
+        Outcome::Wrong(hint, parse_wrong_answer_wait(&text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: String,
+    pub hint: Hint,
+}
+
+/// Wrong answers submitted so far, stored as `[[dayNN.partN]]` arrays, and the time
+/// until which the server does not accept answers.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Guesses {
+    /// Unix time in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    blocked_until: Option<u64>,
+    #[serde(flatten)]
+    days: BTreeMap<String, BTreeMap<String, Vec<Guess>>>,
+}
+
+impl Guesses {
+    /// Reads the guesses at `path`, which are empty if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| format!("Invalid guesses {}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Cannot read guesses {}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(path, toml::to_string(self).unwrap()))
+            .map_err(|e| format!("Cannot write guesses {}: {e}", path.display()))
+    }
+
+    fn guesses(&self, day: u32, part: u32) -> &[Guess] {
+        self.days.get(&format!("day{day:02}"))
+            .and_then(|parts| parts.get(&format!("part{part}")))
+            .map_or(&[], |guesses| guesses.as_slice())
+    }
+
+    pub fn record(&mut self, day: u32, part: u32, answer: &str, hint: Hint) {
+        self.days.entry(format!("day{day:02}")).or_default()
+            .entry(format!("part{part}")).or_default()
+            .push(Guess { answer: answer.to_string(), hint });
+    }
+
+    pub fn block_until(&mut self, until: u64) {
+        self.blocked_until = Some(until);
+    }
+
+    /// Reasons not to submit `answer` at the Unix time `now`: the server would not accept it yet,
+    /// it was already rejected, or it lies outside the bounds from the answers that were too high or too low.
+    pub fn check(&self, day: u32, part: u32, answer: &str, now: u64) -> Result<(), String> {
+        if let Some(until) = self.blocked_until.filter(|until| *until > now) {
+            return Err(format!("The server does not accept answers for another {}s", until - now));
+        }
+        let guesses = self.guesses(day, part);
+        if let Some(guess) = guesses.iter().find(|guess| guess.answer == answer) {
+            let hint = match guess.hint {
+                Hint::TooHigh => " as too high",
+                Hint::TooLow => " as too low",
+                Hint::Neither => "",
+            };
+            return Err(format!("{answer} was already rejected{hint}"));
+        }
+        let Ok(number) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let bound = |hint: Hint| guesses.iter()
+            .filter(move |guess| guess.hint == hint)
+            .filter_map(|guess| guess.answer.parse::<i128>().ok());
+        if let Some(too_high) = bound(Hint::TooHigh).filter(|too_high| number >= *too_high).min() {
+            return Err(format!("{answer} cannot be right, {too_high} was already too high"));
+        }
+        if let Some(too_low) = bound(Hint::TooLow).filter(|too_low| number <= *too_low).max() {
+            return Err(format!("{answer} cannot be right, {too_low} was already too low"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(main: &str) -> String {
+        format!("<!DOCTYPE html>\n<html><head><title>Day 7 - Advent of Code 2023</title></head><body>\n<main>\n<article><p>{main}</p></article>\n</main>\n</body></html>")
+    }
+
+    #[test]
+    fn outcomes() {
+        assert_eq!(parse_outcome(&page("That's the right answer!  You are <em>one gold star</em> closer to restoring snow operations.")), Outcome::Correct);
+        assert_eq!(
+            parse_outcome(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; \
+                there are also some general tips on the <a href=\"/2023/about\">about page</a>.  Please wait one minute before trying again. [<a href=\"/2023/day/7\">Return to Day 7</a>]")),
+            Outcome::Wrong(Hint::TooHigh, Duration::from_secs(60))
+        );
+        assert_eq!(
+            parse_outcome(&page("That's not the right answer; your answer is too low.  please wait 5 minutes before trying again.")),
+            Outcome::Wrong(Hint::TooLow, Duration::from_secs(300))
+        );
+        assert_eq!(
+            parse_outcome(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.")),
+            Outcome::Wrong(Hint::Neither, Duration::from_secs(60))
+        );
+        assert_eq!(
+            parse_outcome(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.")),
+            Outcome::Wait(Duration::from_secs(65))
+        );
+        assert_eq!(
+            parse_outcome(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+            Outcome::WrongLevel
+        );
+        assert_eq!(parse_outcome("<main><p>Something  else</p></main>"), Outcome::Unknown(String::from("Something else")));
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let mut guesses = Guesses::default();
+        guesses.record(7, 1, "500", Hint::TooHigh);
+        guesses.record(7, 1, "100", Hint::TooLow);
+        guesses.record(7, 1, "250", Hint::Neither);
+        guesses.record(7, 1, "300", Hint::TooHigh);
+
+        assert_eq!(guesses.check(7, 1, "299", 0), Ok(()));
+        assert_eq!(guesses.check(7, 1, "250", 0), Err(String::from("250 was already rejected")));
+        assert_eq!(guesses.check(7, 1, "300", 0), Err(String::from("300 was already rejected as too high")));
+        assert_eq!(guesses.check(7, 1, "400", 0), Err(String::from("400 cannot be right, 300 was already too high")));
+        assert_eq!(guesses.check(7, 1, "99", 0), Err(String::from("99 cannot be right, 100 was already too low")));
+        assert_eq!(guesses.check(7, 2, "400", 0), Ok(()));
+        assert_eq!(guesses.check(7, 1, "abc", 0), Ok(()));
+
+        guesses.block_until(1000);
+        assert!(guesses.check(7, 2, "1", 990).unwrap_err().contains("another 10s"));
+        assert_eq!(guesses.check(7, 2, "1", 1000), Ok(()));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut guesses = Guesses::default();
+        guesses.record(7, 1, "500", Hint::TooHigh);
+        guesses.record(12, 2, "3", Hint::Neither);
+        guesses.block_until(1234);
+        let text = toml::to_string(&guesses).unwrap();
+        assert!(text.starts_with("blocked_until = 1234\n"), "{text}");
+        assert!(text.contains("[[day07.part1]]\nanswer = \"500\"\nhint = \"too_high\"\n"), "{text}");
+        assert_eq!(toml::from_str::<Guesses>(&text).unwrap(), guesses);
+    }
+}
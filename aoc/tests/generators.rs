@@ -0,0 +1,37 @@
+use aoc::registry::DAYS;
+use aoc_common::Rng;
+
+fn generate(number: u32, seed: u64, size: usize) -> String {
+    let day = DAYS.iter().find(|day| day.number == number).unwrap();
+    (day.generate)(&mut Rng::new(seed), size).unwrap_or_else(|| panic!("Day {number} has no input generator"))
+}
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for day in &DAYS {
+        for seed in 0..5 {
+            for size in [1, 2, 10] {
+                let input = generate(day.number, seed, size);
+                let parsed = (day.parse)(&input)
+                    .unwrap_or_else(|e| panic!("Day {} seed {seed} size {size}:\n{input}\n{}", day.number, e.diagnostic()));
+                parsed.part1();
+                parsed.part2();
+            }
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_only_depend_on_the_seed() {
+    for day in &DAYS {
+        assert_eq!(generate(day.number, 7, 10), generate(day.number, 7, 10));
+        assert_ne!(generate(day.number, 7, 10), generate(day.number, 8, 10));
+    }
+}
+
+#[test]
+fn generated_inputs_scale_with_size() {
+    assert_eq!(generate(1, 0, 1000).lines().count(), 1000);
+    assert_eq!(generate(10, 0, 40).lines().count(), 40);
+    assert_eq!(generate(13, 0, 25).split("\n\n").count(), 25);
+}
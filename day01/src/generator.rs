@@ -0,0 +1,25 @@
+use aoc_common::Rng;
+
+use crate::DIGIT_WORDS;
+
+/// `size` lines of letters, digits and spelled out digits, each with at least one digit.
+pub(crate) fn input(rng: &mut Rng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.range(1..=12) {
+            match rng.range(0..=9) {
+                0..=5 => line.push(rng.range(b'a'..=b'z') as char),
+                6..=7 => line.push(rng.range(b'1'..=b'9') as char),
+                _ => line.push_str(DIGIT_WORDS[rng.index(DIGIT_WORDS.len())]),
+            }
+        }
+        if !line.contains(|char: char| char.is_ascii_digit()) {
+            let index = rng.range(0..=line.len());
+            line.insert(index, rng.range(b'1'..=b'9') as char);
+        }
+        res.push_str(&line);
+        res.push('\n');
+    }
+    res
+}
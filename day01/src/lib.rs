@@ -0,0 +1,116 @@
+//! Day 1: Trebuchet?!, recovering calibration values from the first and last digit of each line.
+
+use std::fmt::Display;
+
+use aoc_common::{checked_sum, param, parse_lines, Explanation, Param, ParseError, Rng, Solution};
+
+mod generator;
+
+/// The spelled out digits, from one to nine.
+pub(crate) const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// The digits in `line` with their positions and the text they were read from,
+/// including spelled out digits if `spelled_out`. Overlapping words like "eightwo" both count.
+pub fn digit_tokens(line: &str, spelled_out: bool) -> Vec<(usize, &str, u32)> {
+    let mut tokens = Vec::new();
+    for (i, char) in line.char_indices() {
+        if let Some(digit) = char.to_digit(10) {
+            tokens.push((i, &line[i..i + 1], digit));
+        } else if spelled_out {
+            if let Some(digit) = DIGIT_WORDS.iter().position(|word| line[i..].starts_with(word)) {
+                tokens.push((i, DIGIT_WORDS[digit], digit as u32 + 1));
+            }
+        }
+    }
+    tokens
+}
+
+/// The two-digit number made of the first and last of the digit `tokens`, `None` if there are none.
+pub fn calibration_value(tokens: &[(usize, &str, u32)]) -> Option<u32> {
+    Some(10 * tokens.first()?.2 + tokens.last()?.2)
+}
+
+/// How the calibration value of `line` is made up, for one part.
+fn explain_part(line: &str, part: u32, spelled_out: bool) -> String {
+    let tokens = digit_tokens(line, spelled_out);
+    match (calibration_value(&tokens), tokens.first(), tokens.last()) {
+        (Some(value), Some((first_at, first, _)), Some((last_at, last, _))) => format!(
+            "part {part}: {value} from \"{first}\" at {first_at} and \"{last}\" at {last_at}, out of {} digits",
+            tokens.len()
+        ),
+        _ => format!("part {part}: no digits, counts 0"),
+    }
+}
+
+/// Sum of the calibration values of the `lines`, see [`calibration_value`], with the
+/// digits of [`digit_tokens`]. Lines without digits, like "eightwothree" unless `spelled_out`, count 0.
+pub fn sum_calibration_values(lines: &[String], spelled_out: bool) -> u32 {
+    let mut calibration_values = Vec::new();
+    for line in lines {
+        calibration_values.push(calibration_value(&digit_tokens(line, spelled_out)).unwrap_or(0))
+    }
+
+    log::debug!("Calibration values: {calibration_values:?}");
+    checked_sum(calibration_values, "the sum of the calibration values")
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    const PARAMS: &'static [Param] = &[Param::bool("spelled_out_digits")];
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            if digit_tokens(line, true).is_empty() {
+                return Err(ParseError::new("Expected a digit or a spelled out digit").at(line, line));
+            }
+            Ok(String::from(line))
+        })
+    }
+
+    fn part1(lines: &Self::Input) -> impl Display {
+        sum_calibration_values(lines, false)
+    }
+
+    fn part2(lines: &Self::Input) -> Option<impl Display> {
+        Some(sum_calibration_values(lines, param(Self::DAY, "spelled_out_digits", true)))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size))
+    }
+
+    fn explain(lines: &Self::Input) -> Vec<Explanation> {
+        let spelled_out = param(Self::DAY, "spelled_out_digits", true);
+        lines.iter().enumerate()
+            .map(|(i, line)| Explanation::new(i + 1, format!("\"{line}\""))
+                .detail(explain_part(line, 1, false))
+                .detail(explain_part(line, 2, spelled_out)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens() {
+        assert_eq!(digit_tokens("a1eightwo", false), [(1, "1", 1)]);
+        assert_eq!(digit_tokens("a1eightwo", true), [(1, "1", 1), (2, "eight", 8), (6, "two", 2)]);
+        assert_eq!(explain_part("a1eightwo", 2, true), "part 2: 12 from \"1\" at 1 and \"two\" at 6, out of 3 digits");
+        assert_eq!(explain_part("abc", 1, false), "part 1: no digits, counts 0");
+        assert_eq!(sum_calibration_values(&[String::from("a1eightwo"), String::from("x²7")], true), 12 + 77);
+    }
+
+    #[test]
+    fn lines_without_digits() {
+        let error = Day01::parse("1abc2\nabc\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(1)));
+        assert_eq!(Day01::part1(&Day01::parse("a²1b\n").unwrap()).to_string(), "11");
+    }
+}
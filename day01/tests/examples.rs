@@ -0,0 +1,21 @@
+use aoc_common::Solution;
+use day01::Day01;
+
+#[test]
+fn part1_example() {
+    let input = Day01::parse(include_str!("example1.txt")).unwrap();
+    assert_eq!(Day01::part1(&input).to_string(), "142");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day01::parse(include_str!("example2.txt")).unwrap();
+    assert_eq!(Day01::part2(&input).unwrap().to_string(), "281");
+}
+
+#[test]
+fn part1_example2() {
+    // Lines with only spelled out digits have no calibration value in part 1
+    let input = Day01::parse(include_str!("example2.txt")).unwrap();
+    assert_eq!(Day01::part1(&input).to_string(), "209");
+}
@@ -0,0 +1,21 @@
+use aoc_common::Rng;
+
+/// `size` games of one to six drawings with up to 20 cubes of each color.
+pub(crate) fn input(rng: &mut Rng, size: usize) -> String {
+    let mut res = String::new();
+    for number in 1..=size {
+        let drawings: Vec<String> = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let n_colors = rng.range(1..=3);
+                colors[..n_colors].iter()
+                    .map(|color| format!("{} {color}", rng.range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        res.push_str(&format!("Game {number}: {}\n", drawings.join("; ")));
+    }
+    res
+}
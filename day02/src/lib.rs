@@ -0,0 +1,184 @@
+//! Day 2: Cube Conundrum, checking which games of drawn colored cubes are possible.
+
+use std::fmt::Display;
+
+use aoc_common::{checked_add, checked_product, checked_sum, param, parse_lines, parse_number, Explanation, Param, ParseError, Rng, Solution};
+use regex::Regex;
+
+mod generator;
+
+/// The numbers of cubes of each color shown at once.
+#[derive(Debug)]
+pub struct Drawing {
+    pub reds: u32,
+    pub greens: u32,
+    pub blues: u32
+}
+
+impl Drawing {
+    /// The product of the three numbers of cubes.
+    pub fn power(&self) -> u64 {
+        checked_product([self.reds, self.blues, self.greens].map(u64::from), "the power of a set of cubes")
+    }
+}
+
+fn count_cubes(regex: &Regex, line: &str, drawing: &str) -> Result<u32, ParseError> {
+    match regex.captures(drawing) {
+        Some(capture) => parse_number(line, capture.get(1).unwrap().as_str()),
+        None => Ok(0)
+    }
+}
+
+/// A game of one line like "Game 1: 3 blue, 4 red; 1 red, 2 green".
+#[derive(Debug)]
+pub struct Game {
+    pub number: u32,
+    pub drawings: Vec<Drawing>
+}
+
+impl Game {
+    /// Parses one line of the input.
+    pub fn from_string(line: &str) -> Result<Game, ParseError> {
+        let number_regex = Regex::new(r"^Game ([^:]*):").unwrap();
+        let green_regex = Regex::new(r"(\S*) green").unwrap();
+        let blue_regex = Regex::new(r"(\S*) blue").unwrap();
+        let red_regex = Regex::new(r"(\S*) red").unwrap();
+
+        let caps = number_regex.captures(line)
+            .ok_or_else(|| ParseError::new("Expected \"Game <number>:\"").at_index(line, 0))?;
+        let game_number: u32 = parse_number(line, caps.get(1).unwrap().as_str())?;
+
+        let mut drawings: Vec<Drawing> = Vec::new();
+        for drawing in line.split(';') {
+            drawings.push(Drawing {
+                reds: count_cubes(&red_regex, line, drawing)?,
+                greens: count_cubes(&green_regex, line, drawing)?,
+                blues: count_cubes(&blue_regex, line, drawing)?,
+            })
+        }
+        Ok(Game {
+            drawings,
+            number: game_number
+        })
+    }
+
+    /// Whether the game is possible with a bag of the given numbers of cubes.
+    pub fn is_valid_for(&self, n_green: u32, n_red: u32, n_blue: u32) -> bool {
+        for drawing in &self.drawings {
+            if drawing.greens > n_green || drawing.reds > n_red || drawing.blues > n_blue {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// The fewest cubes of each color that make the game possible.
+    pub fn min_possible(&self) -> Drawing {
+        let blues = self.drawings.iter().map(|drawing| drawing.blues).max().unwrap();
+        let greens = self.drawings.iter().map(|drawing| drawing.greens).max().unwrap();
+        let reds = self.drawings.iter().map(|drawing| drawing.reds).max().unwrap();
+        Drawing {
+            greens, blues, reds
+        }
+    }
+
+    /// The power of [`Game::min_possible`].
+    pub fn min_power(&self) -> u64 {
+        self.min_possible().power()
+    }
+
+    /// The drawings, counted from 1, that show more cubes of some color than the bag holds,
+    /// with the colors that are exceeded.
+    pub fn violations(&self, n_green: u32, n_red: u32, n_blue: u32) -> Vec<(usize, Vec<String>)> {
+        self.drawings.iter().enumerate()
+            .filter_map(|(i, drawing)| {
+                let exceeded: Vec<String> = [(drawing.reds, n_red, "red"), (drawing.greens, n_green, "green"), (drawing.blues, n_blue, "blue")]
+                    .into_iter()
+                    .filter(|(shown, in_bag, _)| shown > in_bag)
+                    .map(|(shown, in_bag, color)| format!("{shown} {color}, more than the {in_bag} in the bag"))
+                    .collect();
+                (!exceeded.is_empty()).then_some((i + 1, exceeded))
+            })
+            .collect()
+    }
+}
+
+pub struct Day02;
+
+impl Day02 {
+    /// The cubes in the bag for part 1: green, red and blue.
+    fn bag() -> (u32, u32, u32) {
+        (param(Self::DAY, "green", 13), param(Self::DAY, "red", 12), param(Self::DAY, "blue", 14))
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    const PARAMS: &'static [Param] = &[
+        Param::int("red", 0, u32::MAX as i128),
+        Param::int("green", 0, u32::MAX as i128),
+        Param::int("blue", 0, u32::MAX as i128),
+    ];
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Game::from_string)
+    }
+
+    fn part1(games: &Self::Input) -> impl Display {
+        let (n_green, n_red, n_blue) = Self::bag();
+        let mut sum_valid_game_ids = 0;
+        for game in games {
+            if game.is_valid_for(n_green, n_red, n_blue) {
+                sum_valid_game_ids = checked_add(sum_valid_game_ids, game.number, "the sum of the possible game IDs");
+            }
+        }
+        sum_valid_game_ids
+    }
+
+    fn part2(games: &Self::Input) -> Option<impl Display> {
+        Some(checked_sum(games.iter().map(Game::min_power), "the sum of the powers"))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size))
+    }
+
+    fn explain(games: &Self::Input) -> Vec<Explanation> {
+        let (n_green, n_red, n_blue) = Self::bag();
+        games.iter().enumerate()
+            .map(|(i, game)| {
+                let violations = game.violations(n_green, n_red, n_blue);
+                let summary = if violations.is_empty() {
+                    format!("Game {} is possible, adding {} to part 1", game.number, game.number)
+                } else {
+                    format!("Game {} is impossible", game.number)
+                };
+                let mut explanation = Explanation::new(i + 1, summary);
+                for (drawing, exceeded) in violations {
+                    explanation = explanation.detail(format!("drawing {drawing} shows {}", exceeded.join(" and ")));
+                }
+                let min = game.min_possible();
+                explanation.detail(format!(
+                    "fewest cubes: {} red, {} green, {} blue, power {} for part 2",
+                    min.reds, min.greens, min.blues, min.power()
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn violations() {
+        let game = Game::from_string("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red").unwrap();
+        assert_eq!(game.violations(13, 12, 14), [(1, vec![String::from("20 red, more than the 12 in the bag")])]);
+        assert!(game.violations(13, 20, 14).is_empty());
+        assert_eq!(game.violations(1, 1, 1).len(), 3);
+    }
+}
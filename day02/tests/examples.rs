@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use day02::Day02;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day02::parse(EXAMPLE).unwrap();
+    assert_eq!(Day02::part1(&input).to_string(), "8");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day02::parse(EXAMPLE).unwrap();
+    assert_eq!(Day02::part2(&input).unwrap().to_string(), "2286");
+}
+
+#[test]
+fn power_beyond_u32() {
+    let input = Day02::parse("Game 1: 2000 red, 3000 green, 4000 blue\n").unwrap();
+    assert_eq!(Day02::part2(&input).unwrap().to_string(), "24000000000");
+}
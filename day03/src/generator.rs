@@ -0,0 +1,34 @@
+use aoc_common::Rng;
+
+// Gears are more common than the other symbols
+const SYMBOLS: [char; 12] = ['*', '*', '*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+/// A `size` x `size` schematic with numbers of up to `max_digits` digits, three in the puzzle, and symbols.
+/// With 10 or more digits the gear ratios no longer fit into a `u64`.
+pub(crate) fn input(rng: &mut Rng, size: usize, max_digits: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        let mut row = vec!['.'; size];
+        let mut col = 0;
+        while col < size {
+            let len = rng.range(1..=max_digits.max(1));
+            if rng.chance(0.2) && col + len <= size {
+                row[col] = rng.range(b'1'..=b'9') as char;
+                for cell in &mut row[col + 1..col + len] {
+                    *cell = rng.range(b'0'..=b'9') as char;
+                }
+                col += len;
+                // The cell after a number must not continue it
+                if col < size && rng.chance(0.2) {
+                    row[col] = *rng.choose(&SYMBOLS);
+                }
+            } else if rng.chance(0.1) {
+                row[col] = *rng.choose(&SYMBOLS);
+            }
+            col += 1;
+        }
+        res.extend(row);
+        res.push('\n');
+    }
+    res
+}
@@ -0,0 +1,134 @@
+//! Day 3: Gear Ratios, finding the part numbers and gears around the symbols of an engine schematic.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use aoc_common::{checked_product, checked_sum, param, parse_grid, Color, Frame, Grid, Param, ParseError, Point, Rng, Solution};
+
+mod generator;
+
+/// A number in the schematic, spanning the columns `start..end` of `row`.
+#[derive(Debug)]
+pub struct Number {
+    pub value: u64,
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// All numbers in the schematic, row by row.
+pub fn find_numbers(grid: &Grid<char>) -> Vec<Number> {
+    let mut numbers = Vec::new();
+    for (row, line) in grid.rows().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            if !line[col].is_ascii_digit() {
+                col += 1;
+                continue;
+            }
+            let start = col;
+            while col < line.len() && line[col].is_ascii_digit() {
+                col += 1;
+            }
+            let digits: String = line[start..col].iter().collect();
+            let value = digits.parse()
+                .unwrap_or_else(|_| panic!("The number {digits} in row {} exceeds the range of u64", row + 1));
+            numbers.push(Number { value, row, start, end: col });
+        }
+    }
+    numbers
+}
+
+/// Whether `char` is a symbol, i.e. neither a digit nor a '.'.
+pub fn is_symbol(char: char) -> bool {
+    char != '.' && !char.is_ascii_digit()
+}
+
+/// Positions of the cells around `number` which hold a char matching `predicate`.
+pub fn adjacent_cells<P: Fn(char) -> bool>(grid: &Grid<char>, number: &Number, predicate: P) -> HashSet<Point<usize>> {
+    (number.start..number.end)
+        .flat_map(|col| grid.neighbours8(Point::new(col, number.row)))
+        .filter(|pos| predicate(grid[*pos]))
+        .collect()
+}
+
+/// Sum of the numbers next to a symbol.
+pub fn sum_part_numbers(grid: &Grid<char>) -> u64 {
+    let part_numbers = find_numbers(grid).into_iter()
+        .filter(|number| !adjacent_cells(grid, number, is_symbol).is_empty())
+        .map(|number| number.value);
+    checked_sum(part_numbers, "the sum of the part numbers")
+}
+
+/// The positions of the '*'s next to any number, with the numbers around them.
+pub fn find_gears(grid: &Grid<char>) -> HashMap<Point<usize>, Vec<u64>> {
+    let mut gears: HashMap<Point<usize>, Vec<u64>> = HashMap::new();
+    for number in find_numbers(grid) {
+        for gear in adjacent_cells(grid, &number, |char| char == '*') {
+            gears.entry(gear).or_default().push(number.value);
+        }
+    }
+    log::debug!("Gears with their adjacent numbers: {gears:?}");
+    gears
+}
+
+/// Sum of the products of the two numbers around each '*' with exactly two numbers around it.
+pub fn sum_gear_ratios(grid: &Grid<char>) -> u64 {
+    let ratios = find_gears(grid).into_values().map(
+        |adjacent_numbers| {
+            if adjacent_numbers.len() == 2 {
+                checked_product(adjacent_numbers, "a gear ratio")
+            } else { 0 }
+        }
+    );
+    checked_sum(ratios, "the sum of the gear ratios")
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    const PARAMS: &'static [Param] = &[Param::int("gen_max_digits", 1, usize::MAX as i128)];
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Symbols can be any printable ASCII char
+        let allowed: String = ('!'..='~').collect();
+        parse_grid(input, &allowed, 1)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        sum_part_numbers(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Option<impl Display> {
+        Some(sum_gear_ratios(grid))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size, param(Self::DAY, "gen_max_digits", 3)))
+    }
+
+    fn pictures(grid: &Self::Input) -> Vec<Frame> {
+        let (parts, ignored): (Vec<Number>, Vec<Number>) = find_numbers(grid).into_iter()
+            .partition(|number| !adjacent_cells(grid, number, is_symbol).is_empty());
+        let cells = |numbers: &[Number]| -> Vec<Point<usize>> {
+            numbers.iter().flat_map(|number| (number.start..number.end).map(|col| Point::new(col, number.row))).collect()
+        };
+        let gears: Vec<Point<usize>> = find_gears(grid).into_iter()
+            .filter(|(_, adjacent_numbers)| adjacent_numbers.len() == 2)
+            .map(|(gear, _)| gear)
+            .collect();
+        let frame = Frame::new(grid.clone())
+            .mark_all(cells(&parts), Color::Green)
+            .mark_all(cells(&ignored), Color::Red)
+            .mark_all(gears.iter().copied(), Color::Yellow)
+            .caption(format!("{} part numbers, {} ignored numbers, {} gears", parts.len(), ignored.len(), gears.len()))
+            .legend(Color::Green, "part number, next to a symbol")
+            .legend(Color::Red, "ignored number")
+            .legend(Color::Yellow, "gear, a '*' next to exactly two numbers");
+        vec![frame]
+    }
+}
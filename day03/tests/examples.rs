@@ -0,0 +1,30 @@
+use aoc_common::Solution;
+use day03::Day03;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day03::parse(EXAMPLE).unwrap();
+    assert_eq!(Day03::part1(&input).to_string(), "4361");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day03::parse(EXAMPLE).unwrap();
+    assert_eq!(Day03::part2(&input).unwrap().to_string(), "467835");
+}
+
+#[test]
+fn numbers_beyond_u32() {
+    let input = Day03::parse("4294967296*2\n").unwrap();
+    assert_eq!(Day03::part1(&input).to_string(), "4294967298");
+    assert_eq!(Day03::part2(&input).unwrap().to_string(), "8589934592");
+}
+
+#[test]
+#[should_panic(expected = "Overflow computing a gear ratio")]
+fn overflowing_gear_ratio() {
+    let input = Day03::parse("9999999999*9999999999\n").unwrap();
+    Day03::part2(&input).unwrap().to_string();
+}
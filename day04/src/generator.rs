@@ -0,0 +1,21 @@
+use aoc_common::Rng;
+
+/// `size` cards with 10 winning numbers and 25 numbers you have, all below 100.
+/// Only the share `winning_share` of the cards wins anything, so that the number of copies
+/// in part 2 does not explode. With all cards winning it outgrows a `u64` after about 100 cards.
+/// Like in the puzzle no card wins copies of cards past the end of the table.
+pub(crate) fn input(rng: &mut Rng, size: usize, winning_share: f64) -> String {
+    let mut res = String::new();
+    for number in 1..=size {
+        let mut numbers: Vec<u32> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+        let n_matching = if rng.chance(1.0 - winning_share) { 0 } else { rng.range(1..=5).min(size - number) };
+        let winning = &numbers[..10];
+        let mut have: Vec<u32> = winning[..n_matching].iter().chain(&numbers[10..35 - n_matching]).copied().collect();
+        rng.shuffle(&mut have);
+
+        let format = |numbers: &[u32]| numbers.iter().map(|n| format!("{n:>2}")).collect::<Vec<_>>().join(" ");
+        res.push_str(&format!("Card {number:>3}: {} | {}\n", format(winning), format(&have)));
+    }
+    res
+}
@@ -0,0 +1,155 @@
+//! Day 4: Scratchcards, scoring cards by their winning numbers and the copies they win.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_common::{checked_add, checked_sum, param, parse_lines, parse_number, Explanation, Param, ParseError, Rng, Solution};
+
+mod generator;
+
+fn parse_numbers(line: &str, numbers: &str) -> Result<Vec<u32>, ParseError> {
+    numbers.split_whitespace().map(|n| parse_number(line, n)).collect()
+}
+
+/// A card of one line like "Card 1: 41 48 83 | 83 86 6 31".
+#[derive(Debug)]
+pub struct Card {
+    pub number: u32,
+    pub winning: Vec<u32>,
+    pub have: Vec<u32>,
+}
+
+impl Card {
+    /// Parses one line of the input.
+    pub fn from_string(line: &str) -> Result<Self, ParseError> {
+        let (card, numbers) = line.split_once(':')
+            .ok_or_else(|| ParseError::new("Expected \"Card <number>: ...\"").at_index(line, 0))?;
+
+        let mut card_split = card.split_whitespace();
+        if card_split.next() != Some("Card") {
+            return Err(ParseError::new("Expected \"Card <number>: ...\"").at_index(line, 0));
+        }
+        let number: u32 = parse_number(line, card_split.next().unwrap_or(card))?;
+
+        let (winning, have) = numbers.split_once('|')
+            .ok_or_else(|| ParseError::new("Expected winning numbers and numbers you have, separated by '|'").at(line, numbers))?;
+
+        Ok(Self {
+            number,
+            winning: parse_numbers(line, winning)?,
+            have: parse_numbers(line, have)?,
+        })
+    }
+
+    /// How many of the numbers you have are winning numbers.
+    pub fn get_n_winning(&self) -> u32 {
+        let winning: HashMap<u32, u32> = HashMap::from_iter(
+            self.winning.iter().map(|num| (*num, 1))
+        );
+        self.have.iter().map(
+            |num| *winning.get(num).unwrap_or(&0)
+        ).sum()
+    }
+
+    /// One point for the first winning number, doubled for every further one.
+    pub fn get_points_value(&self) -> u64 {
+        let n_winning = self.get_n_winning();
+        if n_winning == 0 {
+            return 0;
+        }
+        2u64.checked_pow(n_winning - 1)
+            .unwrap_or_else(|| panic!("Overflow computing the points of card {}, which exceeds the range of u64", self.number))
+    }
+}
+
+/// How many copies of each card you end up with, the original included: every card wins one copy
+/// of each of the next cards, as many as it has winning numbers, per copy of itself.
+/// Cards never win copies of cards past the end of the table.
+pub fn count_copies(cards: &[Card]) -> Vec<u64> {
+    let mut card_counts = vec![1u64; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        for j in card.number..last_won(cards, card) {
+            let j = j as usize;
+            card_counts[j] = checked_add(card_counts[j], card_counts[i], "the number of copies of a card");
+        }
+    }
+    card_counts
+}
+
+/// The number of the last card that `card` wins a copy of, or its own number if it wins none.
+fn last_won(cards: &[Card], card: &Card) -> u32 {
+    (card.number + card.get_n_winning()).min(cards.len() as u32)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    const PARAMS: &'static [Param] = &[Param::float("gen_winning_share", 0.0, 1.0)];
+
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let cards = parse_lines(input, Card::from_string)?;
+        // Part 2 uses the card numbers as indices
+        for (i, (card, line)) in cards.iter().zip(input.lines()).enumerate() {
+            if card.number as usize != i + 1 {
+                return Err(ParseError::new(format!("Expected card number {}, found {}", i + 1, card.number))
+                    .on_line(i + 1, line));
+            }
+        }
+        Ok(cards)
+    }
+
+    fn part1(cards: &Self::Input) -> impl Display {
+        checked_sum(cards.iter().map(Card::get_points_value), "the sum of the points")
+    }
+
+    fn part2(cards: &Self::Input) -> Option<impl Display> {
+        Some(checked_sum(count_copies(cards), "the total number of cards"))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size, param(Self::DAY, "gen_winning_share", 0.2)))
+    }
+
+    fn explain(cards: &Self::Input) -> Vec<Explanation> {
+        let copies = count_copies(cards);
+        cards.iter().zip(&copies).enumerate()
+            .map(|(i, (card, n_copies))| {
+                let matches: Vec<String> = card.have.iter().filter(|num| card.winning.contains(num)).map(u32::to_string).collect();
+                let n_winning = card.get_n_winning();
+                let last = last_won(cards, card);
+                let wins = match last - card.number {
+                    0 => String::from("wins no further cards"),
+                    1 => format!("each copy wins a copy of card {last}"),
+                    _ => format!("each copy wins a copy of cards {} to {last}", card.number + 1),
+                };
+                let copies = if *n_copies == 1 { "copy" } else { "copies" };
+                Explanation::new(i + 1, format!("Card {}: {} points, {n_copies} {copies}", card.number, card.get_points_value()))
+                    .detail(format!("{n_winning} winning numbers you have: {}", matches.join(" ")))
+                    .detail(wins)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copies() {
+        let cards = Day04::parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 3\nCard 3: 4 | 5\n").unwrap();
+        assert_eq!(count_copies(&cards), [1, 2, 4]);
+    }
+
+    #[test]
+    fn no_copies_past_the_end() {
+        let cards = Day04::parse("Card 1: 1 2 | 1 2\nCard 2: 3 4 | 3 4\n").unwrap();
+        assert_eq!(count_copies(&cards), [1, 2]);
+        assert_eq!(Day04::part2(&cards).unwrap().to_string(), "3");
+        assert_eq!(Day04::explain(&cards)[1].details[1], "wins no further cards");
+    }
+}
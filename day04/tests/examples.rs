@@ -0,0 +1,31 @@
+use aoc_common::Solution;
+use day04::Day04;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day04::parse(EXAMPLE).unwrap();
+    assert_eq!(Day04::part1(&input).to_string(), "13");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day04::parse(EXAMPLE).unwrap();
+    assert_eq!(Day04::part2(&input).unwrap().to_string(), "30");
+}
+
+#[test]
+#[should_panic(expected = "Overflow computing the number of copies of a card")]
+fn overflowing_copies() {
+    // Every card wins a copy of the next two, so the copies grow like the Fibonacci numbers
+    let cards: String = (1..=100)
+        .map(|number| match number {
+            99 => format!("Card {number}: 1 2 | 1 3\n"),
+            100 => format!("Card {number}: 1 2 | 3 4\n"),
+            _ => format!("Card {number}: 1 2 | 1 2\n"),
+        })
+        .collect();
+    let input = Day04::parse(&cards).unwrap();
+    Day04::part2(&input).unwrap().to_string();
+}
@@ -0,0 +1,34 @@
+use aoc_common::Rng;
+
+const RESOURCES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+/// `size` pairs of seeds and maps with up to `size` ranges each. All numbers stay below
+/// 10 * size^4, so that small inputs can be checked seed by seed, and below 2^32 like in the real almanacs.
+pub(crate) fn input(rng: &mut Rng, size: usize) -> String {
+    let limit = (size as u64).saturating_pow(4).saturating_mul(10).min(1 << 32);
+    let seeds: Vec<String> = (0..size)
+        .map(|_| {
+            let start = rng.range(0..=limit / 2);
+            let len = rng.range(1..=limit / 2 / size as u64);
+            format!("{start} {len}")
+        })
+        .collect();
+    let mut res = format!("seeds: {}\n", seeds.join(" "));
+
+    for resources in RESOURCES.windows(2) {
+        res.push_str(&format!("\n{}-to-{} map:\n", resources[0], resources[1]));
+        // Split the numbers into `size` consecutive source ranges and leave out some of them
+        let mut bounds: Vec<u64> = (1..size).map(|_| rng.range(1..=limit - 1)).collect();
+        bounds.extend([0, limit]);
+        bounds.sort();
+        bounds.dedup();
+        let mut ranges: Vec<(u64, u64)> = bounds.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        rng.shuffle(&mut ranges);
+        for (source, len) in ranges {
+            if rng.chance(0.8) {
+                res.push_str(&format!("{} {source} {len}\n", rng.range(0..=limit - len)));
+            }
+        }
+    }
+    res
+}
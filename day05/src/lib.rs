@@ -0,0 +1,424 @@
+//! Day 5: If You Give A Seed A Fertilizer, mapping seeds through the almanac to their locations.
+
+use std::fmt::Display;
+
+use aoc_common::{checked_add, checked_sub, parse_number, Explanation, ParseError, Rng, Solution};
+
+mod generator;
+
+/// The numbers `start..end`.
+#[derive(Debug)]
+pub struct Interval {
+    pub start: u64,
+    pub end: u64
+}
+
+/// A line of a map, moving the `range_length` numbers from `source_start_range` on to `dest_start_range`.
+#[derive(Debug)]
+pub struct Range {
+    pub dest_start_range: u64,
+    pub source_start_range: u64,
+    pub range_length: u64,
+}
+
+impl Range {
+    /// Parses a line of three numbers: destination start, source start and length.
+    pub fn from_string(string: &str) -> Result<Self, ParseError> {
+        let parts: Vec<&str> = string.split_whitespace().collect();
+        if parts.len() != 3 {
+            return Err(ParseError::new(format!("Expected three numbers, found {}", parts.len())).at(string, string));
+        }
+        let range = Self {
+            dest_start_range: parse_number(string, parts[0])?,
+            source_start_range: parse_number(string, parts[1])?,
+            range_length: parse_number(string, parts[2])?
+        };
+        if range.source_start_range.max(range.dest_start_range).checked_add(range.range_length).is_none() {
+            return Err(ParseError::new("The range ends past the largest number, u64::MAX").at(string, parts[2]));
+        }
+        Ok(range)
+    }
+
+    /// The end of the source range, exclusive.
+    #[track_caller]
+    pub fn source_end(&self) -> u64 {
+        checked_add(self.source_start_range, self.range_length, "the end of a source range")
+    }
+
+    /// The end of the destination range, exclusive.
+    #[track_caller]
+    pub fn dest_end(&self) -> u64 {
+        checked_add(self.dest_start_range, self.range_length, "the end of a destination range")
+    }
+
+    /// The number `input` from the source range is moved to.
+    #[track_caller]
+    pub fn to_dest(&self, input: u64) -> u64 {
+        checked_add(checked_sub(input, self.source_start_range, "an offset into a source range"), self.dest_start_range, "a moved number")
+    }
+
+    /// Splits `interval` into the parts outside of the source range, which are left as they are,
+    /// and the part inside it, moved to the destination: (unapplied, applied).
+    pub fn apply(&self, interval: &Interval) -> (Vec<Interval>, Vec<Interval>) {
+        if interval.start >= self.source_end() || interval.end <= self.source_start_range {
+            return (vec![Interval { start: interval.start, end: interval.end }], Vec::new())
+        }
+        if interval.start < self.source_end() && interval.start >= self.source_start_range {
+              if interval.end <= self.source_end() {
+                  (Vec::new(), vec![Interval {
+                      start: self.to_dest(interval.start),
+                      end: self.to_dest(interval.end)
+                  }])
+              } else {
+                  (vec![
+                      Interval { start: self.source_end(), end: interval.end }
+                  ], vec![Interval {
+                      start: self.to_dest(interval.start),
+                      end: self.dest_end()
+                  }])
+              }
+        }
+        // interval.start < self.source_start_range
+        else {
+            if interval.end <= self.source_end() {
+                (vec![
+                    Interval { start: interval.start, end: self.source_start_range }
+                ], vec![Interval {
+                    start: self.dest_start_range,
+                    end: self.to_dest(interval.end)
+                }])
+            } else {
+                (vec![
+                    Interval { start: interval.start, end: self.source_start_range },
+                    Interval { start: self.source_end(), end: interval.end },
+                ], vec![Interval {
+                    start: self.dest_start_range,
+                    end: self.dest_end()
+                }])
+            }
+        }
+    }
+}
+
+/// Map converting the numbers of the resource `from` into the numbers of the resource `to`.
+#[derive(Debug)]
+pub struct Map {
+    pub from: String,
+    pub to: String,
+    pub ranges: Vec<Range>
+}
+
+impl Map {
+    /// The range whose source contains `input`, if any.
+    pub fn range_for(&self, input: u64) -> Option<&Range> {
+        self.ranges.iter().find(|range| range.source_start_range <= input && input < range.source_end())
+    }
+
+    /// The number `input` is converted to, which is `input` itself if no range contains it.
+    pub fn apply(&self, input: u64) -> u64 {
+        match self.range_for(input) {
+            Some(range) => range.to_dest(input),
+            None => input,
+        }
+    }
+
+    /// The intervals `intervals` are converted to, which may be split up along the ranges.
+    pub fn apply_to_intervals(&self, intervals: Vec<Interval>) -> Vec<Interval> {
+        let mut applied = Vec::new();
+        let mut unapplied = intervals;
+
+        for range in &self.ranges {
+            let mut new_applied = Vec::new();
+            let mut still_unapplied = Vec::new();
+            for interval in &unapplied {
+                let (mut unappl, mut appl) = range.apply(interval);
+                new_applied.append(&mut appl);
+                still_unapplied.append(&mut unappl);
+            }
+            applied.extend(new_applied);
+            unapplied = still_unapplied;
+        }
+        // Values not matched remain as they are
+        applied.extend(unapplied);
+        applied
+    }
+}
+
+/// Converts seed `intervals` through the chain of `maps` into location intervals.
+pub fn apply_all_intervals(maps: &Vec<Map>, mut intervals: Vec<Interval>) -> Vec<Interval> {
+    let mut resource_name = String::from("seed");
+    while resource_name != "location" {
+        for map in maps {
+            if map.from == resource_name {
+                resource_name = map.to.clone();
+                intervals = map.apply_to_intervals(intervals);
+            }
+        }
+    }
+    intervals
+
+}
+
+/// The maps the seed `seed_no` is converted by on its way to a location, each with the number it gives.
+/// Loops forever if the maps do not lead from seed to location.
+pub fn seed_path(maps: &[Map], seed_no: u64) -> Vec<(&Map, u64)> {
+    let mut path = Vec::new();
+    let mut resource_name = "seed";
+    let mut resource_no = seed_no;
+    while resource_name != "location" {
+        for map in maps {
+            if map.from == resource_name {
+                resource_name = &map.to;
+                resource_no = map.apply(resource_no);
+                path.push((map, resource_no));
+            }
+        }
+    }
+    path
+}
+
+/// Converts the seed `seed_no` through the chain of `maps` into its location.
+/// Loops forever if the maps do not lead from seed to location.
+pub fn apply_all(maps: &[Map], seed_no: u64) -> u64 {
+    seed_path(maps, seed_no).last().map_or(seed_no, |(_, location)| *location)
+}
+
+fn parse_seeds(line: &str) -> Result<Vec<u64>, ParseError> {
+    let seeds = match line.split_once(':') {
+        Some(("seeds", seeds)) => seeds,
+        _ => return Err(ParseError::new("Expected \"seeds: <numbers>\"").at_index(line, 0)),
+    };
+    let seeds = seeds.split_whitespace()
+        .map(|x| parse_number(line, x))
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+    if seeds.is_empty() {
+        return Err(ParseError::new("Expected at least one seed").at_index(line, 0));
+    }
+    if seeds.len() % 2 != 0 {
+        // Part 2 reads them as pairs of start and length
+        return Err(ParseError::new("Expected an even number of seeds").at_index(line, 0));
+    }
+    Ok(seeds)
+}
+
+fn parse_map_header(line: &str) -> Result<Map, ParseError> {
+    let error = || ParseError::new("Expected \"<source>-to-<destination> map:\"").at_index(line, 0);
+    let name = line.strip_suffix(" map:").ok_or_else(error)?;
+    let (map_from, map_to) = name.split_once("-to-").ok_or_else(error)?;
+    Ok(Map {from: String::from(map_from), to: String::from(map_to), ranges: Vec::new()})
+}
+
+// Every resource has to be converted into the next one, until reaching a location
+fn check_maps_lead_to_location(maps: &[Map]) -> Result<(), ParseError> {
+    let mut resource_name = "seed";
+    for _ in 0..=maps.len() {
+        if resource_name == "location" {
+            return Ok(());
+        }
+        resource_name = &maps.iter()
+            .find(|map| map.from == resource_name)
+            .ok_or_else(|| ParseError::new(format!("Expected a map from {resource_name} to another resource")))?
+            .to;
+    }
+    Err(ParseError::new("Expected maps leading from seed to location, found a cycle"))
+}
+
+// seeds, maps
+fn parse_input(lines: &[&str]) -> Result<(Vec<u64>, Vec<Map>), ParseError> {
+    let first_line = lines.first().ok_or(ParseError::new("Empty input"))?;
+    let seeds = parse_seeds(first_line).map_err(|e| e.on_line(1, first_line))?;
+    if let Some(line) = lines.get(1).filter(|line| !line.is_empty()) {
+        return Err(ParseError::new("Expected an empty line after the seeds").at(line, line).on_line(2, line));
+    }
+
+    let mut maps: Vec<Map> = Vec::new();
+
+    let mut i = 1;
+
+    'maps: loop {
+        i += 1;
+        let Some(line) = lines.get(i) else {
+            break 'maps;
+        };
+        let mut map = parse_map_header(line).map_err(|e| e.on_line(i + 1, line))?;
+
+        'ranges: loop {
+            i += 1;
+            if i >= lines.len() {
+                maps.push(map);
+                break 'maps;
+            } else if lines[i].is_empty() {
+                maps.push(map);
+                break 'ranges
+            } else {
+                map.ranges.push(Range::from_string(lines[i]).map_err(|e| e.on_line(i + 1, lines[i]))?)
+            }
+
+        }
+    }
+
+    check_maps_lead_to_location(&maps)?;
+    Ok((seeds, maps))
+
+}
+
+/// The seeds and the maps leading from seed to location, checked when parsing.
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<Map>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let (seeds, maps) = parse_input(&lines)?;
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(almanac: &Self::Input) -> impl Display {
+        almanac.seeds.iter().map(|s| apply_all(&almanac.maps, *s)).min().unwrap()
+    }
+
+    fn part2(almanac: &Self::Input) -> Option<impl Display> {
+        let seed_intervals = almanac.seeds.chunks(2).map(
+            |w| Interval {start: w[0], end: checked_add(w[0], w[1], "the end of a seed range")}
+        ).collect();
+
+        Some(apply_all_intervals(&almanac.maps, seed_intervals).iter().map(|s| s.start).min().unwrap())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size))
+    }
+
+    fn explain(almanac: &Self::Input) -> Vec<Explanation> {
+        let lowest = almanac.seeds.iter().map(|seed| apply_all(&almanac.maps, *seed)).min();
+        almanac.seeds.iter()
+            .map(|seed| {
+                let path = seed_path(&almanac.maps, *seed);
+                let location = path.last().map_or(*seed, |(_, location)| *location);
+                let lowest = if Some(location) == lowest { ", the lowest one" } else { "" };
+                let mut explanation = Explanation::new(1, format!("Seed {seed} ends up at location {location}{lowest}"));
+                let mut previous = *seed;
+                for (map, number) in path {
+                    let detail = match map.range_for(previous) {
+                        Some(range) => format!(
+                            "{} {previous} is {} {number} by the range {} {} {}",
+                            map.from, map.to, range.dest_start_range, range.source_start_range, range.range_length
+                        ),
+                        None => format!("{} {previous} is {} {number}, as no range contains it", map.from, map.to),
+                    };
+                    explanation = explanation.detail(detail);
+                    previous = number;
+                }
+                explanation
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::assert_matches_oracle;
+
+    use super::*;
+
+    fn range() -> Range {
+        Range { dest_start_range: 50, source_start_range: 98, range_length: 2 }
+    }
+
+    fn bounds(intervals: &[Interval]) -> Vec<(u64, u64)> {
+        intervals.iter().map(|interval| (interval.start, interval.end)).collect()
+    }
+
+    #[test]
+    fn apply_outside() {
+        let (unapplied, applied) = range().apply(&Interval { start: 10, end: 20 });
+        assert_eq!(bounds(&unapplied), [(10, 20)]);
+        assert!(applied.is_empty());
+    }
+
+    #[test]
+    fn apply_inside() {
+        let (unapplied, applied) = range().apply(&Interval { start: 98, end: 100 });
+        assert!(unapplied.is_empty());
+        assert_eq!(bounds(&applied), [(50, 52)]);
+    }
+
+    #[test]
+    fn apply_overlapping() {
+        let (unapplied, applied) = range().apply(&Interval { start: 95, end: 99 });
+        assert_eq!(bounds(&unapplied), [(95, 98)]);
+        assert_eq!(bounds(&applied), [(50, 51)]);
+
+        let (unapplied, applied) = range().apply(&Interval { start: 99, end: 105 });
+        assert_eq!(bounds(&unapplied), [(100, 105)]);
+        assert_eq!(bounds(&applied), [(51, 52)]);
+    }
+
+    #[test]
+    fn range_past_u64_max() {
+        let error = Range::from_string("0 18446744073709551615 2").unwrap_err();
+        assert_eq!(error.message(), "The range ends past the largest number, u64::MAX");
+        assert!(Range::from_string("18446744073709551614 0 1").is_ok());
+    }
+
+    #[test]
+    fn blank_line_after_seeds() {
+        let error = Day05::parse("seeds: 79 14\nseed-to-location map:\n50 98 2\n").err().unwrap();
+        assert_eq!(error.message(), "Expected an empty line after the seeds");
+        assert_eq!(error.line(), Some(2));
+    }
+
+    #[test]
+    fn path() {
+        let almanac = Day05::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-location map:\n0 81 1\n").unwrap();
+        let path: Vec<(&str, u64)> = seed_path(&almanac.maps, 79).into_iter().map(|(map, number)| (map.to.as_str(), number)).collect();
+        assert_eq!(path, [("soil", 81), ("location", 0)]);
+        assert_eq!(apply_all(&almanac.maps, 14), 14);
+    }
+
+    #[test]
+    fn no_seeds() {
+        let error = Day05::parse("seeds:\n\nseed-to-location map:\n1 2 3\n").err().unwrap();
+        assert_eq!((error.message(), error.line()), ("Expected at least one seed", Some(1)));
+    }
+
+    #[test]
+    fn apply_covering() {
+        let (unapplied, applied) = range().apply(&Interval { start: 90, end: 110 });
+        assert_eq!(bounds(&unapplied), [(90, 98), (100, 110)]);
+        assert_eq!(bounds(&applied), [(50, 52)]);
+    }
+
+    // Maps every single seed of the ranges
+    fn lowest_location_brute_force(almanac: &Almanac) -> u64 {
+        almanac.seeds.chunks(2)
+            .flat_map(|w| w[0]..w[0] + w[1])
+            .map(|seed| apply_all(&almanac.maps, seed))
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn intervals_match_single_seeds() {
+        assert_matches_oracle("day05-intervals", 1000, 4, generator::input, |input| {
+            let almanac = Day05::parse(input).ok()?;
+            let single_seeds = almanac.seeds.iter().map(|seed| Interval { start: *seed, end: seed + 1 }).collect();
+            let fast = apply_all_intervals(&almanac.maps, single_seeds).iter().map(|s| s.start).min().unwrap();
+            let slow: u64 = Day05::part1(&almanac).to_string().parse().unwrap();
+            if fast != slow {
+                return Some(format!("Part 1: intervals of single seeds give {fast}, applying the maps to each seed gives {slow}"));
+            }
+            let fast: u64 = Day05::part2(&almanac).unwrap().to_string().parse().unwrap();
+            let slow = lowest_location_brute_force(&almanac);
+            (fast != slow).then(|| format!("Part 2: intervals give {fast}, mapping every seed gives {slow}"))
+        });
+    }
+}
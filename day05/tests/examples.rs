@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day05::Day05;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day05::parse(EXAMPLE).unwrap();
+    assert_eq!(Day05::part1(&input).to_string(), "35");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day05::parse(EXAMPLE).unwrap();
+    assert_eq!(Day05::part2(&input).unwrap().to_string(), "46");
+}
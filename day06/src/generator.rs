@@ -0,0 +1,18 @@
+use aoc_common::Rng;
+
+/// Four races taking up to `size` milliseconds (at least 2), each with a record that can be beaten.
+/// The single race of part 2 joins the digits, so its numbers overflow for sizes of more than a few hundred.
+pub(crate) fn input(rng: &mut Rng, size: usize) -> String {
+    let max_time = size.max(2) as u64;
+    let (times, distances): (Vec<u64>, Vec<u64>) = (0..4)
+        .map(|_| {
+            let time = rng.range(max_time.div_ceil(2).max(2)..=max_time);
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.range(0..=best - 1))
+        })
+        .unzip();
+
+    let width = distances.iter().max().unwrap().to_string().len() + 2;
+    let row = |numbers: &[u64]| numbers.iter().map(|n| format!("{n:>width$}")).collect::<String>();
+    format!("Time:    {}\nDistance:{}\n", row(&times), row(&distances))
+}
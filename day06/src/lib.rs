@@ -0,0 +1,149 @@
+//! Day 6: Wait For It, counting the ways to win boat races.
+
+use std::fmt::Display;
+
+use aoc_common::{checked_add, checked_mul, checked_product, parse_number, ParseError, Rng, Solution};
+
+mod generator;
+
+/// Number of wind times that beat the record `distance` in a race of `time` milliseconds.
+pub fn n_winning(time: u64, distance: u64) -> u64 {
+    // The distance w * (time - w) grows up to w = time / 2 and then falls symmetrically,
+    // so find the shortest winning wind time by bisection
+    let travels = |wind_time: u64| wind_time as u128 * (time - wind_time) as u128;
+    if travels(time / 2) <= distance as u128 {
+        return 0;
+    }
+    let (mut lo, mut hi) = (0, time / 2);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if travels(mid) > distance as u128 {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    time - 2 * lo + 1
+}
+
+/// Product of the numbers of ways to win each race.
+pub fn get_n_winning_possibilities(times: &[u64], distances: &[u64]) -> u64 {
+    let n_winning = times.iter().zip(distances.iter())
+        .map(|(time, distance)| n_winning(*time, *distance));
+    checked_product(n_winning, "the product of the numbers of ways to win")
+}
+
+/// The number written by the digits of all `numbers` one after another, panics if it exceeds a `u64`.
+#[track_caller]
+pub fn join_digits(numbers: &[u64], what: &str) -> u64 {
+    let mut joined = 0u64;
+    for digit in numbers.iter().flat_map(|n| n.to_string().into_bytes()) {
+        joined = checked_add(checked_mul(joined, 10, what), (digit - b'0') as u64, what);
+    }
+    joined
+}
+
+fn parse_numbers(line: &str, name: &str) -> Result<Vec<u64>, ParseError> {
+    match line.split_once(':') {
+        Some((n, numbers)) if n == name => numbers.split_whitespace()
+            .map(|s| parse_number(line, s))
+            .collect(),
+        _ => Err(ParseError::new(format!("Expected \"{name}: <numbers>\"")).at_index(line, 0)),
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    // times, distances
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        if lines.len() != 2 {
+            return Err(ParseError::new(format!("Expected two lines, found {}", lines.len())));
+        }
+        let times = parse_numbers(lines[0], "Time").map_err(|e| e.on_line(1, lines[0]))?;
+        let distances = parse_numbers(lines[1], "Distance").map_err(|e| e.on_line(2, lines[1]))?;
+        if times.is_empty() {
+            return Err(ParseError::new("Expected at least one race").on_line(1, lines[0]));
+        }
+        if times.len() != distances.len() {
+            return Err(ParseError::new(format!("Expected {} distances, found {}", times.len(), distances.len()))
+                .on_line(2, lines[1]));
+        }
+        Ok((times, distances))
+    }
+
+    fn part1((times, distances): &Self::Input) -> impl Display {
+        get_n_winning_possibilities(times, distances)
+    }
+
+    fn part2((times, distances): &Self::Input) -> Option<impl Display> {
+        // The spaces between the numbers are bad kerning, so there is only one race
+        Some(get_n_winning_possibilities(
+            &[join_digits(times, "the time of the single race")],
+            &[join_digits(distances, "the record of the single race")]
+        ))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::assert_matches_oracle;
+
+    use super::*;
+
+    fn boat_travels_far_enough(time_available: u64, wind_time: u64, distance_needed: u64) -> bool {
+        let speed = wind_time;
+        let travel_time = time_available - wind_time;
+        let distance_travelled = travel_time * speed;
+        distance_needed < distance_travelled
+    }
+
+    // Tries every wind time, which is too slow for part 2 of real inputs
+    fn n_winning_brute_force(time_available: u64, distance_needed: u64) -> u64 {
+        (0..time_available)
+            .filter(|wind_time| boat_travels_far_enough(time_available, *wind_time, distance_needed))
+            .count() as u64
+    }
+
+    #[test]
+    fn n_winning_edge_cases() {
+        assert_eq!(n_winning(7, 9), 4);
+        assert_eq!(n_winning(30, 200), 9);
+        assert_eq!(n_winning(4, 4), 0);
+        assert_eq!(n_winning(4, 3), 1);
+        assert_eq!(n_winning(0, 0), 0);
+    }
+
+    #[test]
+    fn joined_digits() {
+        assert_eq!(join_digits(&[7, 15, 30], "x"), 71530);
+        assert_eq!(join_digits(&[18446744073, 709551615], "x"), u64::MAX);
+        assert!(Day06::parse("Time:\nDistance:\n").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow computing the time of the single race, which exceeds the range of u64")]
+    fn overflowing_single_race() {
+        Day06::part2(&Day06::parse("Time: 18446744073 709551616\nDistance: 1 2\n").unwrap());
+    }
+
+    #[test]
+    fn n_winning_matches_brute_force() {
+        assert_matches_oracle("day06-n-winning", 2000, 60, generator::input, |input| {
+            let (times, distances) = Day06::parse(input).ok()?;
+            times.iter().zip(&distances).find_map(|(time, distance)| {
+                let (fast, slow) = (n_winning(*time, *distance), n_winning_brute_force(*time, *distance));
+                (fast != slow).then(|| format!("Race of {time} ms with record {distance}: {fast} ways, brute force finds {slow}"))
+            })
+        });
+    }
+}
@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day06::Day06;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day06::parse(EXAMPLE).unwrap();
+    assert_eq!(Day06::part1(&input).to_string(), "288");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day06::parse(EXAMPLE).unwrap();
+    assert_eq!(Day06::part2(&input).unwrap().to_string(), "71503");
+}
@@ -0,0 +1,19 @@
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+const CARDS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+/// `size` different hands, at most all 13^5 possible ones, with bids up to `max_bid`, 1000 in the puzzle.
+/// With bids near `u32::MAX`, the total winnings outgrow a `u64` from about 130000 hands.
+pub(crate) fn input(rng: &mut Rng, size: usize, max_bid: u32) -> String {
+    let mut hands = HashSet::new();
+    let mut res = String::new();
+    while hands.len() < size.min(13usize.pow(5)) {
+        let hand: String = (0..5).map(|_| *rng.choose(&CARDS)).collect();
+        if hands.insert(hand.clone()) {
+            res.push_str(&format!("{hand} {}\n", rng.range(1..=max_bid.max(1))));
+        }
+    }
+    res
+}
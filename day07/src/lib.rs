@@ -0,0 +1,273 @@
+//! Day 7: Camel Cards, ranking poker-like hands with and without jokers.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_common::{checked_add, checked_mul, param, parse_lines, parse_number, Explanation, Param, ParseError, Rng, Solution};
+use itertools::{Itertools};
+
+mod generator;
+
+/// The types of hands, from weakest to strongest.
+#[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Clone)]
+pub enum HandValues {
+    HighCard,
+    OnePair,
+    TwoPairs,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandValues {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HandValues::HighCard => "high card",
+            HandValues::OnePair => "one pair",
+            HandValues::TwoPairs => "two pairs",
+            HandValues::ThreeOfAKind => "three of a kind",
+            HandValues::FullHouse => "full house",
+            HandValues::FourOfAKind => "four of a kind",
+            HandValues::FiveOfAKind => "five of a kind",
+        }
+    }
+}
+
+/// Five cards with a bid. Hands are ordered by their type first, then card by card,
+/// where '*' is a joker and the weakest card.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Hand {
+    cards: String,
+    card_counts: HashMap<char, u32>,
+    value: HandValues,
+    bid: u32,
+}
+
+impl Hand {
+    /// Parses a line of five cards and the bid, like "32T3K 765".
+    pub fn from_string(line: &str) -> Result<Hand, ParseError> {
+        let Some((cards, bid)) = line.split_whitespace().collect_tuple() else {
+            return Err(ParseError::new("Expected cards and bid").at(line, line));
+        };
+        if let Some((index, card)) = cards.char_indices().find(|(_, c)| !"23456789TJQKA".contains(*c)) {
+            return Err(ParseError::new("Expected one of \"23456789TJQKA\"").at(line, &cards[index..index + card.len_utf8()]));
+        }
+        if cards.len() != 5 {
+            return Err(ParseError::new(format!("Expected five cards, found {}", cards.len())).at(line, cards));
+        }
+        Ok(Hand::new(String::from(cards), parse_number(line, bid)?))
+    }
+
+    /// The hand with every 'J' as joker instead of jack, written as '*'.
+    pub fn with_jokers(&self) -> Hand {
+        Hand::new(self.cards.replace('J', "*"), self.bid)
+    }
+
+    /// Hand of the cards "23456789TJQKA" or '*' for jokers. Panics unless there are five cards.
+    pub fn new(cards: String, bid: u32) -> Hand {
+        let mut card_counts = HashMap::new();
+        for card in cards.chars() {
+            *card_counts.entry(card).or_insert(0) += 1;
+        }
+
+        let n_jokers = card_counts.remove(&'*').unwrap_or(0);
+
+        let mut n_equal: Vec<u32> = card_counts.values().cloned().collect::<Vec<u32>>();
+        n_equal.sort();
+        n_equal.reverse();
+
+        if n_equal.is_empty() {
+            n_equal.push(0)
+        }
+        // Take all jokers to be the value of the card where
+        // we own the most copies. Since we don't have
+        // straights or flushes, this is always the best option.
+        n_equal[0] += n_jokers;
+
+
+        let hand_value = match n_equal[0] {
+            1 => HandValues::HighCard,
+            2 => if n_equal[1] == 2 {HandValues::TwoPairs} else {HandValues::OnePair},
+            3 => if n_equal[1] == 2 {HandValues::FullHouse} else {HandValues::ThreeOfAKind},
+            4 => HandValues::FourOfAKind,
+            5 => HandValues::FiveOfAKind,
+            _ => { panic!("Cannot determine hand value") }
+        };
+
+        Hand {
+            cards,
+            card_counts,
+            bid,
+            value: hand_value
+        }
+    }
+
+    pub fn cards(&self) -> &str {
+        &self.cards
+    }
+
+    pub fn value(&self) -> &HandValues {
+        &self.value
+    }
+
+    pub fn bid(&self) -> u32 {
+        self.bid
+    }
+
+    /// The card the jokers count as: the most common other card, the strongest one on a tie.
+    /// `None` without jokers, or with nothing but jokers.
+    pub fn joker_target(&self) -> Option<char> {
+        if !self.cards.contains('*') {
+            return None;
+        }
+        self.card_counts.iter()
+            .max_by_key(|(card, n)| (**n, "*23456789TJQKA".find(**card)))
+            .map(|(card, _)| *card)
+    }
+}
+
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.value == other.value {
+            for (card_s, card_o) in self.cards.chars().zip(other.cards.chars()) {
+                let ps = "*23456789TJQKA".find(card_s).unwrap();
+                let po = "*23456789TJQKA".find(card_o).unwrap();
+                if ps != po {
+                    return ps.cmp(&po);
+                }
+
+            }
+        }
+        self.value.cmp(&other.value)
+    }
+}
+
+/// The bid of a hand with the given rank multiplied by that rank.
+pub fn winnings(rank: usize, bid: u32) -> u64 {
+    checked_mul(rank as u64, bid as u64, "the winnings of a hand")
+}
+
+/// Sum of the bids, each multiplied by the rank of its hand.
+pub fn total_winnings(mut hands: Vec<Hand>) -> u64 {
+    hands.sort();
+
+    let mut total_value = 0u64;
+    for (i, hand) in hands.iter().enumerate() {
+        total_value = checked_add(total_value, winnings(i + 1, hand.bid), "the total winnings");
+    }
+    total_value
+}
+
+/// The rank of every hand, from 1 for the weakest, as in [`total_winnings`].
+pub fn ranks(hands: &[Hand]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..hands.len()).collect();
+    order.sort_by(|a, b| hands[*a].cmp(&hands[*b]));
+    let mut ranks = vec![0; hands.len()];
+    for (rank, i) in order.into_iter().enumerate() {
+        ranks[i] = rank + 1;
+    }
+    ranks
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    const PARAMS: &'static [Param] = &[Param::int("gen_max_bid", 1, u32::MAX as i128)];
+
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Hand::from_string)
+    }
+
+    fn part1(hands: &Self::Input) -> impl Display {
+        total_winnings(hands.clone())
+    }
+
+    fn part2(hands: &Self::Input) -> Option<impl Display> {
+        Some(total_winnings(hands.iter().map(Hand::with_jokers).collect()))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size, param(Self::DAY, "gen_max_bid", 1000)))
+    }
+
+    fn explain(hands: &Self::Input) -> Vec<Explanation> {
+        let with_jokers: Vec<Hand> = hands.iter().map(Hand::with_jokers).collect();
+        let (ranks, joker_ranks) = (ranks(hands), ranks(&with_jokers));
+        hands.iter().zip(&with_jokers).enumerate()
+            .map(|(i, (hand, joker_hand))| {
+                let jokers = match (joker_hand.cards.matches('*').count(), joker_hand.joker_target()) {
+                    (0, _) => String::from("without jacks"),
+                    (n, Some(target)) => format!("with {n} J as {target}"),
+                    (_, None) => String::from("with only jokers"),
+                };
+                Explanation::new(i + 1, format!("{} bids {}", hand.cards, hand.bid))
+                    .detail(format!(
+                        "part 1: {}, rank {} of {}, winning {}",
+                        hand.value.name(), ranks[i], hands.len(), winnings(ranks[i], hand.bid)
+                    ))
+                    .detail(format!(
+                        "part 2: {} {jokers}, rank {} of {}, winning {}",
+                        joker_hand.value.name(), joker_ranks[i], hands.len(), winnings(joker_ranks[i], hand.bid)
+                    ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &str) -> Hand {
+        Hand::new(String::from(cards), 0)
+    }
+
+    #[test]
+    fn hand_values() {
+        assert_eq!(hand("23456").value, HandValues::HighCard);
+        assert_eq!(hand("A23A4").value, HandValues::OnePair);
+        assert_eq!(hand("23432").value, HandValues::TwoPairs);
+        assert_eq!(hand("TTT98").value, HandValues::ThreeOfAKind);
+        assert_eq!(hand("23332").value, HandValues::FullHouse);
+        assert_eq!(hand("AA8AA").value, HandValues::FourOfAKind);
+        assert_eq!(hand("AAAAA").value, HandValues::FiveOfAKind);
+    }
+
+    #[test]
+    fn cmp_by_value_then_cards() {
+        assert!(hand("2AAAA") > hand("KKQQJ"));
+        assert!(hand("33332") > hand("2AAAA"));
+        assert!(hand("77888") > hand("77788"));
+        assert_eq!(hand("KK677").cmp(&hand("KK677")), Ordering::Equal);
+    }
+
+    #[test]
+    fn jokers() {
+        assert_eq!(hand("QJJQ2").with_jokers().value, HandValues::FourOfAKind);
+        assert_eq!(hand("JJJJJ").with_jokers().value, HandValues::FiveOfAKind);
+        // Jokers are the weakest card on a tie
+        assert!(hand("JKKK2").with_jokers() < hand("QQQQ2").with_jokers());
+        assert_eq!(hand("KTJJT").with_jokers().joker_target(), Some('T'));
+        assert_eq!(hand("JJJJJ").with_jokers().joker_target(), None);
+        assert_eq!(hand("KTJJT").joker_target(), None);
+    }
+
+    #[test]
+    fn ranks_like_total_winnings() {
+        let hands = vec![hand("KK677"), hand("32T3K"), hand("QQQJA")];
+        assert_eq!(ranks(&hands), [2, 1, 3]);
+    }
+}
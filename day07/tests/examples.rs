@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use day07::Day07;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day07::parse(EXAMPLE).unwrap();
+    assert_eq!(Day07::part1(&input).to_string(), "6440");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day07::parse(EXAMPLE).unwrap();
+    assert_eq!(Day07::part2(&input).unwrap().to_string(), "5905");
+}
+
+#[test]
+fn winnings_beyond_u32() {
+    let input = Day07::parse("32T3K 4294967295\nKK677 4294967295\n").unwrap();
+    assert_eq!(Day07::part1(&input).to_string(), "12884901885");
+}
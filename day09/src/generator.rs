@@ -0,0 +1,28 @@
+use aoc_common::Rng;
+
+/// `size` histories of 21 values, following polynomials of degree up to 5, with the first value of each
+/// level of differences up to `max_difference`, 20 in the puzzle. From about 10^15 on, the sums of the
+/// extrapolated values outgrow an `i64`, and a bit further the values themselves do.
+pub(crate) fn input(rng: &mut Rng, size: usize, max_difference: i64) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        // Start with constant differences and sum them up degree times,
+        // choosing the first value of each level of differences
+        let degree = rng.range(0..=5);
+        // In i128, so that values beyond i64 can be written too
+        let mut values = vec![rng.range(-5i64..=5) as i128; 21];
+        for _ in 0..degree {
+            let max_difference = max_difference.saturating_abs();
+            let mut current = rng.range(-max_difference..=max_difference) as i128;
+            for value in &mut values {
+                let difference = *value;
+                *value = current;
+                current += difference;
+            }
+        }
+        let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        res.push_str(&values.join(" "));
+        res.push('\n');
+    }
+    res
+}
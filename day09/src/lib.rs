@@ -0,0 +1,100 @@
+//! Day 9: Mirage Maintenance, extrapolating sequences by their differences.
+
+use std::fmt::Display;
+
+use aoc_common::{checked_add, checked_mul, checked_sub, checked_sum, param, parse_lines, parse_number, Param, ParseError, Rng, Solution};
+
+mod generator;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    const PARAMS: &'static [Param] = &[Param::int("gen_max_difference", 0, i64::MAX as i128)];
+
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input,
+            |l| l.split_whitespace().map(
+                |d| parse_number(l, d)
+            ).collect()
+        )
+    }
+
+    fn part1(histories: &Self::Input) -> impl Display {
+        checked_sum(histories.iter().map(
+            |h| process_pt1(h)
+        ), "the sum of the next values")
+    }
+
+    fn part2(histories: &Self::Input) -> Option<impl Display> {
+        Some(checked_sum(histories.iter().map(
+            |h| process_pt2(h)
+        ), "the sum of the previous values"))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size, param(Self::DAY, "gen_max_difference", 20)))
+    }
+}
+
+/// The next value of `history`.
+pub fn process_pt1(history: &[i64]) -> i64 {
+    let mut result = 0;
+    let mut current_sequence = history;
+    let mut sequence;
+    while !current_sequence.iter().all(|d| *d == 0) {
+        result = checked_add(result, current_sequence[current_sequence.len() - 1], "a next value");
+        sequence = process_one_step(current_sequence);
+        current_sequence = &sequence;
+    }
+    result
+
+}
+
+/// The value before the first one of `history`.
+pub fn process_pt2(history: &[i64]) -> i64 {
+    let mut result = 0;
+    let mut step_number = 0;
+    let mut current_sequence = history;
+    let mut sequence;
+    while !current_sequence.iter().all(|d| *d == 0) {
+        let sign = if step_number % 2 == 0 { 1 } else {-1 };
+        result = checked_add(result, checked_mul(current_sequence[0], sign, "a previous value"), "a previous value");
+        sequence = process_one_step(current_sequence);
+        current_sequence = &sequence;
+        step_number += 1;
+    }
+    result
+
+}
+
+/// The differences between neighbouring values.
+pub fn process_one_step(history: &[i64]) -> Vec<i64> {
+    history.windows(2)
+        .map(|w| checked_sub(w[1], w[0], "a difference")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_step() {
+        assert_eq!(process_one_step(&[1, 3, 6, 10, 15, 21]), [2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn extrapolate_forwards() {
+        assert_eq!(process_pt1(&[0, 3, 6, 9, 12, 15]), 18);
+        assert_eq!(process_pt1(&[10, 13, 16, 21, 30, 45]), 68);
+    }
+
+    #[test]
+    fn extrapolate_backwards() {
+        assert_eq!(process_pt2(&[0, 3, 6, 9, 12, 15]), -3);
+        assert_eq!(process_pt2(&[10, 13, 16, 21, 30, 45]), 5);
+    }
+}
@@ -0,0 +1,30 @@
+use aoc_common::Solution;
+use day09::Day09;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day09::parse(EXAMPLE).unwrap();
+    assert_eq!(Day09::part1(&input).to_string(), "114");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day09::parse(EXAMPLE).unwrap();
+    assert_eq!(Day09::part2(&input).unwrap().to_string(), "2");
+}
+
+#[test]
+fn values_beyond_i32() {
+    let input = Day09::parse("0 3000000000 6000000000\n").unwrap();
+    assert_eq!(Day09::part1(&input).to_string(), "9000000000");
+    assert_eq!(Day09::part2(&input).unwrap().to_string(), "-3000000000");
+}
+
+#[test]
+#[should_panic(expected = "Overflow computing a difference, which exceeds the range of i64")]
+fn overflowing_difference() {
+    let input = Day09::parse("-9223372036854775808 9223372036854775807\n").unwrap();
+    Day09::part1(&input).to_string();
+}
@@ -0,0 +1,83 @@
+use aoc_common::{Direction, Grid, Point, Rng};
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+/// A random tree on a `k` x `k` lattice of nodes, drawn into a `2k - 1` x `2k - 1` grid
+/// with the nodes at even positions and the edges between them.
+fn random_tree(rng: &mut Rng, k: usize) -> Grid<bool> {
+    let mut cells = Grid::new(2 * k - 1, 2 * k - 1, false);
+    let n_nodes = rng.range((k * k).div_ceil(2)..=k * k);
+    // Edges leading out of the tree, with the node they lead to
+    let mut frontier = Vec::new();
+    let (row, col) = (2 * rng.index(k), 2 * rng.index(k));
+    let mut node = Point::new(col, row);
+    for i in 0..n_nodes {
+        cells[node] = true;
+        frontier.extend(cells.neighbours4(node).map(|edge| (edge, Point::new(2 * edge.x - node.x, 2 * edge.y - node.y))));
+        if i + 1 == n_nodes {
+            break;
+        }
+        let (edge, next) = loop {
+            let (edge, next) = frontier.swap_remove(rng.index(frontier.len()));
+            if !cells[next] {
+                break (edge, next);
+            }
+        };
+        cells[edge] = true;
+        node = next;
+    }
+    cells
+}
+
+/// The pipe at the corner (`row`, `col`) between the cells, if it lies on the outline of the inside cells.
+fn outline_pipe(inside: &Grid<bool>, row: usize, col: usize) -> Option<char> {
+    let is_inside = |row: isize, col: isize| inside.pos(Point::new(col, row)).is_some_and(|pos| inside[pos]);
+    let (row, col) = (row as isize, col as isize);
+    let north = is_inside(row - 1, col - 1) != is_inside(row - 1, col);
+    let south = is_inside(row, col - 1) != is_inside(row, col);
+    let west = is_inside(row - 1, col - 1) != is_inside(row, col - 1);
+    let east = is_inside(row - 1, col) != is_inside(row, col);
+    match (north, south, west, east) {
+        (true, true, false, false) => Some('|'),
+        (false, false, true, true) => Some('-'),
+        (true, false, false, true) => Some('L'),
+        (true, false, true, false) => Some('J'),
+        (false, true, true, false) => Some('7'),
+        (false, true, false, true) => Some('F'),
+        _ => None,
+    }
+}
+
+/// A `size` x `size` map (at least 3 x 3) of junk pipes, with a loop around the outline of a random tree.
+pub(crate) fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let tree = random_tree(rng, ((size + 1) / 4).max(1));
+    // Every cell of the tree becomes 2 x 2 tiles, so that some tiles are enclosed by the loop
+    let inside = Grid::from_fn(2 * tree.width(), 2 * tree.height(), |pos| tree[Point::new(pos.x / 2, pos.y / 2)]);
+    let n_corners = inside.width() + 1;
+    let (row_offset, col_offset) = (rng.range(0..=size - n_corners), rng.range(0..=size - n_corners));
+
+    let mut map = Grid::from_fn(size, size, |_| if rng.chance(0.5) { '.' } else { *rng.choose(&PIPES) });
+    let mut the_loop = Vec::new();
+    for row in 0..n_corners {
+        for col in 0..n_corners {
+            if let Some(pipe) = outline_pipe(&inside, row, col) {
+                let pos = Point::new(col + col_offset, row + row_offset);
+                map[pos] = pipe;
+                the_loop.push(pos);
+            }
+        }
+    }
+
+    // Like in the real maps, only the two pipes of the loop connect to the start
+    let start = *rng.choose(&the_loop);
+    for (direction, pipes) in [(Direction::N, "|7F"), (Direction::S, "|LJ"), (Direction::W, "-LF"), (Direction::E, "-J7")] {
+        if let Some(neighbour) = map.offset(start, direction.vector()) {
+            if !the_loop.contains(&neighbour) && pipes.contains(map[neighbour]) {
+                map[neighbour] = '.';
+            }
+        }
+    }
+    map[start] = 'S';
+    map.to_string()
+}
@@ -0,0 +1,234 @@
+//! Day 10: Pipe Maze, following the loop of pipes through the start and counting the tiles it encloses.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_common::{parse_grid, record, Color, Direction, Frame, Grid, ParseError, Point, Rng, Solution};
+
+mod generator;
+
+fn find_start(grid: &Grid<char>, start_marker: char) -> Point<usize> {
+    grid.position(|char| *char == start_marker).expect("Could not find start marker!")
+}
+
+/// The two directions in which `pipe_part` leads.
+pub fn pipe_directions(pipe_part: char) -> Option<[Direction; 2]> {
+    match pipe_part {
+        '|' => Some([Direction::N, Direction::S]),
+        '-' => Some([Direction::W, Direction::E]),
+        'L' => Some([Direction::N, Direction::E]),
+        'J' => Some([Direction::N, Direction::W]),
+        '7' => Some([Direction::S, Direction::W]),
+        'F' => Some([Direction::S, Direction::E]),
+        _ => None,
+    }
+}
+
+/// The one of the `directions` of a pipe part that leaves it, when entered heading towards `heading`.
+fn exit(directions: [Direction; 2], heading: Direction) -> Direction {
+    if directions[0] == heading.opposite() { directions[1] } else { directions[0] }
+}
+
+/// Moves one step along the pipes from `position`, which was entered heading towards `heading`.
+/// Returns the new position and heading.
+pub fn find_next(grid: &Grid<char>, position: Point<usize>, heading: Direction) -> (Point<usize>, Direction) {
+    let directions = pipe_directions(grid[position])
+        .unwrap_or_else(|| panic!("The loop leads off the pipes at {position:?}!"));
+    let exit = exit(directions, heading);
+    let next = position.step(exit).filter(|next| grid.get(*next).is_some()).expect("The loop leads off the grid!");
+    (next, exit)
+}
+
+fn connects(grid: &Grid<char>, position: Point<usize>, direction: Direction) -> bool {
+    position.step(direction)
+        .and_then(|neighbour| grid.get(neighbour))
+        .and_then(|pipe_part| pipe_directions(*pipe_part))
+        .is_some_and(|directions| directions.contains(&direction.opposite()))
+}
+
+/// The pipe part hidden under the start marker, i.e. the one connecting to both
+/// neighbours that connect back to the start.
+fn start_pipe_part(grid: &Grid<char>, start: Point<usize>) -> Option<char> {
+    "|-LJ7F".chars()
+        .find(|pipe_part| pipe_directions(*pipe_part).unwrap().iter().all(|direction| connects(grid, start, *direction)))
+}
+
+/// Follows the pipes from `start` around the loop, in a grid without start marker.
+/// Returns the position of the pipe that leads off the loop if it is not closed.
+fn check_loop(grid: &Grid<char>, start: Point<usize>) -> Result<(), Point<usize>> {
+    let mut heading = pipe_directions(grid[start]).unwrap()[0].opposite();
+    let mut current = start;
+    // Every pipe connects to two others at most, so the loop returns to the start or breaks off
+    loop {
+        let exit = exit(pipe_directions(grid[current]).unwrap(), heading);
+        if !connects(grid, current, exit) {
+            return Err(current);
+        }
+        (current, heading) = (current.step(exit).unwrap(), exit);
+        if current == start {
+            return Ok(());
+        }
+    }
+}
+
+/// Checks that there is exactly one start marker and that it is part of a closed loop.
+fn check_start(input: &str, grid: &Grid<char>) -> Result<(), ParseError> {
+    let line = |row: usize| input.lines().nth(row).unwrap_or_default();
+    let error = |message: &str, point: Point<usize>| {
+        ParseError::new(message).at_index(line(point.y), point.x).on_line(point.y + 1, line(point.y))
+    };
+    let mut starts = grid.iter().filter(|(_, char)| **char == 'S').map(|(pos, _)| pos);
+    let start = starts.next().ok_or_else(|| ParseError::new("Expected a start marker 'S'"))?;
+    if let Some(other) = starts.next() {
+        return Err(error("Expected only one start marker 'S'", other));
+    }
+    let Some(start_pipe_part) = start_pipe_part(grid, start) else {
+        return Err(error("Expected two pipes to connect to the start", start));
+    };
+    let mut pipes = grid.clone();
+    pipes[start] = start_pipe_part;
+    check_loop(&pipes, start).map_err(|point| error("The loop from the start breaks off here", point))
+}
+
+/// Returns the position of the start and the grid with the start marker
+/// replaced by the actual pipe part.
+pub fn replace_start(grid: &Grid<char>) -> (Point<usize>, Grid<char>) {
+    let start = find_start(grid, 'S');
+    let mut grid = grid.clone();
+    grid[start] = start_pipe_part(&grid, start).expect("The start is not part of a loop!");
+    (start, grid)
+}
+
+/// The positions of the pipes of the loop through `start`, in a grid without start marker.
+pub fn find_loop(grid: &Grid<char>, start: Point<usize>) -> HashSet<Point<usize>> {
+    // Pretend to have entered the start through one of its two ends to walk off through the other
+    let heading = pipe_directions(grid[start]).unwrap()[0].opposite();
+    let (mut current, mut heading) = find_next(grid, start, heading);
+
+    let mut the_loop = HashSet::from([current]);
+
+    while current != start {
+        (current, heading) = find_next(grid, current, heading);
+        the_loop.insert(current);
+        record(|| Frame::new(grid.clone())
+            .mark_all(the_loop.iter().copied(), Color::Green)
+            .mark(current, Color::Red)
+            .caption(format!("Following the loop: {} pipes", the_loop.len())));
+    }
+    the_loop
+}
+
+/// The tiles enclosed by `the_loop`, in a grid without start marker.
+pub fn find_inside(grid: &Grid<char>, the_loop: &HashSet<Point<usize>>) -> Vec<Point<usize>> {
+    // Idea: Iterate over the tiles in the grid, row by row.
+    // A specific tile is inside the loop iff the number of intersections with the loop,
+    // counted from the beginning of the row, is odd (Jordan curve theorem).
+    let mut inside = Vec::new();
+
+    for (i, line) in grid.rows().enumerate() {
+        let mut n_intersections = 0;
+        let mut prev_opening_char = 'x';
+
+        for (j, char) in line.iter().enumerate() {
+            if !the_loop.contains(&Point::new(j, i)) {
+                if n_intersections % 2 == 1 {
+                    inside.push(Point::new(j, i));
+                }
+            } else {
+                match char {
+                    '|' => n_intersections += 1,
+                    'F' | 'L' => prev_opening_char = *char,
+                    '7' => if prev_opening_char == 'L' {
+                        n_intersections += 1;
+                    },
+                    'J' => if prev_opening_char == 'F' {
+                        n_intersections += 1
+                    }
+                    _ => continue, // Only '-' is left
+                }
+            }
+        }
+        record(|| Frame::new(grid.clone())
+            .mark_all(the_loop.iter().copied(), Color::Green)
+            .mark_all(inside.iter().copied(), Color::Yellow)
+            .caption(format!("Row {i}: {} tiles inside", inside.len())));
+    }
+    inside
+}
+
+/// The number of tiles enclosed by the loop through `start`, in a grid without start marker.
+pub fn count_inside(grid: &Grid<char>, start: Point<usize>) -> usize {
+    find_inside(grid, &find_loop(grid, start)).len()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = parse_grid(input, "|-LJ7F.S", 1)?;
+        check_start(input, &grid)?;
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        let (start, grid) = replace_start(grid);
+        find_loop(&grid, start).len() / 2
+    }
+
+    fn part2(grid: &Self::Input) -> Option<impl Display> {
+        let (start, grid) = replace_start(grid);
+        Some(count_inside(&grid, start))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size))
+    }
+
+    fn pictures(grid: &Self::Input) -> Vec<Frame> {
+        let (start, pipes) = replace_start(grid);
+        let the_loop = find_loop(&pipes, start);
+        let inside = find_inside(&pipes, &the_loop);
+        let inside_set: HashSet<Point<usize>> = inside.iter().copied().collect();
+
+        let mut frame = Frame::new(grid.clone())
+            .mark_all(the_loop.iter().copied(), Color::Green)
+            .mark(start, Color::Red);
+        for (pos, _) in grid.iter() {
+            if inside_set.contains(&pos) {
+                frame = frame.shade(pos, Color::Yellow, 0.6);
+            } else if !the_loop.contains(&pos) {
+                frame = frame.shade(pos, Color::Blue, 0.3);
+            }
+        }
+        let frame = frame
+            .caption(format!("Loop of {} pipes, farthest {} steps away, enclosing {} tiles", the_loop.len(), the_loop.len() / 2, inside.len()))
+            .legend(Color::Red, "start")
+            .legend(Color::Green, "loop")
+            .legend(Color::Yellow, "inside the loop")
+            .legend(Color::Blue, "outside the loop");
+        vec![frame]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(input: &str) -> (String, Option<usize>, Option<usize>) {
+        let error = Day10::parse(input).unwrap_err();
+        (error.message().to_string(), error.line(), error.column())
+    }
+
+    #[test]
+    fn invalid_starts() {
+        assert_eq!(error("F-7\n|.|\nL-J\n"), (String::from("Expected a start marker 'S'"), None, None));
+        assert_eq!(error("S-7\n|.|\nL-S\n"), (String::from("Expected only one start marker 'S'"), Some(3), Some(3)));
+        assert_eq!(error("S-7\n..|\nL-J\n"), (String::from("Expected two pipes to connect to the start"), Some(1), Some(1)));
+        assert_eq!(error("S-7\n|.|\nL-.\n"), (String::from("The loop from the start breaks off here"), Some(2), Some(3)));
+        assert!(Day10::parse("S-7\n|.|\nL-J\n").is_ok());
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::Solution;
+use day10::Day10;
+
+fn answers(example: &str) -> (String, String) {
+    let input = Day10::parse(example).unwrap();
+    let part1 = Day10::part1(&input).to_string();
+    let part2 = Day10::part2(&input).unwrap().to_string();
+    (part1, part2)
+}
+
+#[test]
+fn example1() {
+    assert_eq!(answers(include_str!("example1.txt")), (String::from("4"), String::from("1")));
+}
+
+#[test]
+fn example2() {
+    assert_eq!(answers(include_str!("example2.txt")), (String::from("8"), String::from("1")));
+}
+
+#[test]
+fn example3() {
+    assert_eq!(answers(include_str!("example3.txt")), (String::from("23"), String::from("4")));
+}
+
+#[test]
+fn example4() {
+    assert_eq!(answers(include_str!("example4.txt")), (String::from("70"), String::from("8")));
+}
+
+#[test]
+fn example5() {
+    assert_eq!(answers(include_str!("example5.txt")), (String::from("80"), String::from("10")));
+}
@@ -0,0 +1,16 @@
+use aoc_common::Rng;
+
+/// A `size` x `size` image with a few galaxies, where some rows and columns are empty.
+pub(crate) fn input(rng: &mut Rng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.15)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.chance(0.15)).collect();
+    let mut res = String::new();
+    for is_empty_row in &empty_rows {
+        for is_empty_col in &empty_cols {
+            let is_galaxy = !is_empty_row && !is_empty_col && rng.chance(0.05);
+            res.push(if is_galaxy { '#' } else { '.' });
+        }
+        res.push('\n');
+    }
+    res
+}
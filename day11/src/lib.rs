@@ -0,0 +1,131 @@
+//! Day 11: Cosmic Expansion, summing the distances between galaxies in an expanding universe.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_common::{checked_add, checked_mul, checked_sum, param, parse_grid, Color, Frame, Grid, Param, ParseError, Point, Rng, Solution};
+use itertools::Itertools;
+
+mod generator;
+
+/// The indices of the rows and of the columns without galaxies.
+pub fn find_empty_rows_and_cols(grid: &Grid<char>) -> (HashSet<usize>, HashSet<usize>) {
+    // Determine empty rows and columns
+    let mut empty_rows = HashSet::new();
+    let mut empty_cols = HashSet::new();
+    for (i, line) in grid.rows().enumerate() {
+        if !line.contains(&'#') {
+            empty_rows.insert(i);
+        }
+    }
+    for (i, mut col) in grid.columns().enumerate() {
+        if col.all(|c| *c == '.') {
+            empty_cols.insert(i);
+        }
+    }
+    log::debug!("Empty rows: {:?}, empty cols: {:?}", empty_rows, empty_cols);
+    (empty_rows, empty_cols)
+
+}
+
+pub fn find_galaxies(universe: &Grid<char>) -> HashSet<Point<usize>> {
+    universe.iter()
+        .filter(|(_, char)| **char == '#')
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+/// Distance between two galaxies, where every empty row or column between them counts `empty_space_length` times.
+pub fn dist(
+    galaxy_1: &Point<usize>, galaxy_2: &Point<usize>,
+    empty_rows: &HashSet<usize>, empty_cols: &HashSet<usize>,
+    empty_space_length: i64) -> i64 {
+
+    // Number of empty rows or columns strictly between the coordinates a and b
+    let n_empty_between = |a: usize, b: usize, empty: &HashSet<usize>| {
+        empty.iter().filter(|i| a.min(b) < **i && **i < a.max(b)).count() as i64
+    };
+    let n_empty = n_empty_between(galaxy_1.x, galaxy_2.x, empty_cols) + n_empty_between(galaxy_1.y, galaxy_2.y, empty_rows);
+
+    let expansion = checked_mul(n_empty, empty_space_length - 1, "a distance between galaxies");
+    checked_add(galaxy_1.manhattan(*galaxy_2) as i64, expansion, "a distance between galaxies")
+}
+
+/// Sum of the distances between all pairs of galaxies, see [`dist`].
+pub fn sum_of_distances(universe: &Grid<char>, empty_space_length: i64) -> i64 {
+    let (empty_rows, empty_cols) = find_empty_rows_and_cols(universe);
+    let galaxies = find_galaxies(universe);
+
+    let distances = galaxies.iter().tuple_combinations().map(
+        |(g1, g2)|  dist(g1, g2, &empty_rows, &empty_cols, empty_space_length)
+    );
+    checked_sum(distances, "the sum of the distances")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    const PARAMS: &'static [Param] = &[
+        Param::int("expansion_part1", 1, i64::MAX as i128),
+        Param::int("expansion_part2", 1, i64::MAX as i128),
+    ];
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input, ".#", 1)
+    }
+
+    fn part1(universe: &Self::Input) -> impl Display {
+        sum_of_distances(universe, param(Self::DAY, "expansion_part1", 2))
+    }
+
+    fn part2(universe: &Self::Input) -> Option<impl Display> {
+        Some(sum_of_distances(universe, param(Self::DAY, "expansion_part2", 1_000_000)))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size))
+    }
+
+    fn pictures(universe: &Self::Input) -> Vec<Frame> {
+        let (empty_rows, empty_cols) = find_empty_rows_and_cols(universe);
+        let galaxies = find_galaxies(universe);
+
+        let mut frame = Frame::new(universe.clone()).mark_all(galaxies.iter().copied(), Color::Yellow);
+        for (pos, _) in universe.iter() {
+            if empty_rows.contains(&pos.y) || empty_cols.contains(&pos.x) {
+                frame = frame.shade(pos, Color::Blue, 0.5);
+            }
+        }
+        let frame = frame
+            .caption(format!("{} galaxies, {} empty rows and {} empty columns", galaxies.len(), empty_rows.len(), empty_cols.len()))
+            .legend(Color::Yellow, "galaxy")
+            .legend(Color::Blue, "empty row or column, expanding");
+        vec![frame]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dist_expands_empty_space() {
+        let empty_rows = HashSet::from([3, 7]);
+        let empty_cols = HashSet::from([2, 5, 8]);
+        let (a, b) = (Point::new(3, 0), Point::new(7, 8));
+        assert_eq!(dist(&a, &b, &empty_rows, &empty_cols, 2), 15);
+        assert_eq!(dist(&b, &a, &empty_rows, &empty_cols, 2), 15);
+        let (c, d) = (Point::new(0, 2), Point::new(9, 6));
+        assert_eq!(dist(&c, &d, &empty_rows, &empty_cols, 2), 17);
+        assert_eq!(dist(&c, &d, &empty_rows, &empty_cols, 10), 49);
+    }
+
+    #[test]
+    fn dist_without_empty_space() {
+        assert_eq!(dist(&Point::new(1, 1), &Point::new(0, 4), &HashSet::new(), &HashSet::new(), 1_000_000), 4);
+    }
+}
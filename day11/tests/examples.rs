@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day11::Day11;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day11::parse(EXAMPLE).unwrap();
+    assert_eq!(Day11::part1(&input).to_string(), "374");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day11::parse(EXAMPLE).unwrap();
+    assert_eq!(Day11::part2(&input).unwrap().to_string(), "82000210");
+}
@@ -0,0 +1,20 @@
+use aoc_common::Rng;
+
+/// `size` rows of 3 to 20 springs, about half of them unknown.
+pub(crate) fn input(rng: &mut Rng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        let len = rng.range(3..=20);
+        let mut springs: Vec<char> = (0..len).map(|_| if rng.chance(0.5) { '#' } else { '.' }).collect();
+        if !springs.contains(&'#') {
+            springs[rng.index(len)] = '#';
+        }
+        let counts: Vec<String> = springs.split(|spring| *spring == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        let record: String = springs.iter().map(|spring| if rng.chance(0.5) { '?' } else { *spring }).collect();
+        res.push_str(&format!("{record} {}\n", counts.join(",")));
+    }
+    res
+}
@@ -0,0 +1,145 @@
+//! Day 12: Hot Springs, counting the arrangements of damaged springs that fit the condition records.
+
+use std::fmt::Display;
+
+use aoc_common::{checked_add, checked_sum, parse_lines, parse_number, ParseError, Rng, Solution};
+use itertools::Itertools;
+
+mod generator;
+
+/// A row of springs, operational ('.'), damaged ('#') or unknown ('?'), with the sizes of
+/// the groups of damaged springs in it.
+#[derive(Debug)]
+pub struct ConditionRecord {
+    pub bin_part: String,
+    pub consecutive_damaged_springs: Vec<usize>
+}
+
+impl ConditionRecord {
+    /// Parses a line like "???.### 1,1,3".
+    pub fn from_string(line: &str) -> Result<ConditionRecord, ParseError> {
+        let Some((bin_part, counts)) = line.split_whitespace().collect_tuple() else {
+            return Err(ParseError::new("Expected springs and counts of damaged springs").at(line, line));
+        };
+        if let Some((index, spring)) = bin_part.char_indices().find(|(_, c)| !".#?".contains(*c)) {
+            return Err(ParseError::new("Expected one of \".#?\"").at(line, &bin_part[index..index + spring.len_utf8()]));
+        }
+        let groups: Vec<&str> = counts.split(',').collect();
+        let consecutive_damaged_springs: Vec<usize> = groups.iter()
+            .map(|d| parse_number(line, d))
+            .collect::<Result<_, _>>()?;
+        if let Some((group, _)) = groups.iter().zip(&consecutive_damaged_springs).find(|(_, count)| **count == 0) {
+            return Err(ParseError::new("Expected groups of at least one damaged spring").at(line, group));
+        }
+        Ok(Self { bin_part: String::from(bin_part), consecutive_damaged_springs })
+    }
+
+    /// Number of ways to replace the unknown springs so that the damaged ones form the recorded groups.
+    pub fn count_arrangements(&self) -> u64 {
+        let springs = self.bin_part.as_bytes();
+        let groups = &self.consecutive_damaged_springs;
+        let (n, m) = (springs.len(), groups.len());
+        // ways[i][j]: arrangements of springs[i..] into the groups[j..]
+        let mut ways = vec![vec![0u64; m + 1]; n + 2];
+        ways[n][m] = 1;
+        ways[n + 1][m] = 1;
+        for i in (0..n).rev() {
+            for j in 0..=m {
+                if springs[i] != b'#' {
+                    ways[i][j] = checked_add(ways[i][j], ways[i + 1][j], "the number of arrangements");
+                }
+                if springs[i] != b'.' && j < m {
+                    // The next group starts here and needs an operational spring (or the end) after it
+                    let end = i + groups[j];
+                    let fits = end <= n && !springs[i..end].contains(&b'.') && springs.get(end) != Some(&b'#');
+                    if fits {
+                        ways[i][j] = checked_add(ways[i][j], ways[end + 1][j + 1], "the number of arrangements");
+                    }
+                }
+            }
+        }
+        ways[0][0]
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Vec<ConditionRecord>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, ConditionRecord::from_string)
+    }
+
+    fn part1(records: &Self::Input) -> impl Display {
+        checked_sum(records.iter().map(ConditionRecord::count_arrangements), "the sum of the arrangements")
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::assert_matches_oracle;
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn matches_possibility(record: &ConditionRecord, possibility: &str) -> bool {
+        let consecutive_damaged: Vec<usize> = possibility.split('.')
+            .filter(|c| !c.is_empty())
+            .map(|c| c.len())
+            .collect();
+        consecutive_damaged == record.consecutive_damaged_springs
+    }
+
+    // Tries every combination of the unknown springs
+    fn count_arrangements_brute_force(record: &ConditionRecord) -> u64 {
+        let n_wildcards = record.bin_part.matches('?').count();
+        // The product of no iterators is empty rather than a single empty combination
+        if n_wildcards == 0 {
+            return matches_possibility(record, &record.bin_part) as u64;
+        }
+        let mut n_found = 0;
+        for combination in vec![[".", "#"]; n_wildcards].iter().multi_cartesian_product() {
+            let mut known_parts = record.bin_part.split('?');
+            let mut test_string = String::from(known_parts.next().unwrap());
+            for (c, k) in combination.iter().zip(known_parts) {
+                test_string += c;
+                test_string += k;
+            }
+            if matches_possibility(record, &test_string) {
+                n_found += 1;
+            }
+        }
+        n_found
+    }
+
+    #[test]
+    fn count_arrangements_of_examples() {
+        let counts: Vec<u64> = ["???.### 1,1,3", "?###???????? 3,2,1", "# 1", "#.# 1", "??? 4"].iter()
+            .map(|line| ConditionRecord::from_string(line).unwrap().count_arrangements())
+            .collect();
+        assert_eq!(counts, [1, 10, 1, 0, 0]);
+    }
+
+    fn mismatch(input: &str) -> Option<String> {
+        let records = Day12::parse(input).ok()?;
+        records.iter().find_map(|record| {
+            let (fast, slow) = (record.count_arrangements(), count_arrangements_brute_force(record));
+            (fast != slow).then(|| format!("{record:?}: {fast} arrangements, brute force finds {slow}"))
+        })
+    }
+
+    #[test]
+    fn count_arrangements_matches_brute_force() {
+        // Groups of 0 springs, where both used to disagree, are not valid input
+        assert!(Day12::parse("? 0\n").is_err());
+        assert_eq!(mismatch("? 0\n"), None);
+        assert_matches_oracle("day12-arrangements", 1000, 3, generator::input, mismatch);
+    }
+}
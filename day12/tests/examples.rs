@@ -0,0 +1,10 @@
+use aoc_common::Solution;
+use day12::Day12;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day12::parse(EXAMPLE).unwrap();
+    assert_eq!(Day12::part1(&input).to_string(), "21");
+}
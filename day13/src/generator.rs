@@ -0,0 +1,45 @@
+use aoc_common::{Grid, Point, Rng};
+
+fn reflects_at_row(pattern: &Grid<bool>, row: usize) -> bool {
+    (0..row.min(pattern.height() - row)).all(|i| pattern.row(row - 1 - i) == pattern.row(row + i))
+}
+
+fn reflects_at_col(pattern: &Grid<bool>, col: usize) -> bool {
+    (0..col.min(pattern.width() - col)).all(|i| pattern.column(col - 1 - i).eq(pattern.column(col + i)))
+}
+
+/// A random pattern of 5 to 17 rows and columns, mirrored at exactly one line.
+fn pattern(rng: &mut Rng) -> Grid<bool> {
+    loop {
+        let (width, height) = (rng.range(5..=17), rng.range(5..=17));
+        let mut pattern = Grid::from_fn(width, height, |_| rng.chance(0.5));
+        if rng.chance(0.5) {
+            let col = rng.range(1..=width - 1);
+            for i in 0..col.min(width - col) {
+                for row in 0..height {
+                    pattern[Point::new(col + i, row)] = pattern[Point::new(col - 1 - i, row)];
+                }
+            }
+        } else {
+            let row = rng.range(1..=height - 1);
+            for i in 0..row.min(height - row) {
+                for col in 0..width {
+                    pattern[Point::new(col, row + i)] = pattern[Point::new(col, row - 1 - i)];
+                }
+            }
+        }
+        let n_lines = (1..width).filter(|col| reflects_at_col(&pattern, *col)).count()
+            + (1..height).filter(|row| reflects_at_row(&pattern, *row)).count();
+        if n_lines == 1 {
+            return pattern;
+        }
+    }
+}
+
+/// `size` patterns, each with a single line of reflection.
+pub(crate) fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| pattern(rng).map(|rock| if *rock { '#' } else { '.' }).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
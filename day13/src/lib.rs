@@ -0,0 +1,125 @@
+//! Day 13: Point of Incidence, finding the lines of reflection in patterns of ash and rocks.
+
+use std::fmt::Display;
+
+use aoc_common::{checked_mul, checked_sum, parse_grid, Color, Divider, Frame, Grid, ParseError, Rng, Solution};
+
+mod generator;
+
+/// Whether the pattern is mirrored between the rows `row_no - 1` and `row_no`. Panics if `row_no` is 0.
+pub fn test_reflection_row(grid: &Grid<char>, row_no: usize) -> bool {
+    let (mut above, mut below) = (row_no - 1, row_no);
+    while below < grid.height() {
+        if grid.row(above) == grid.row(below) {
+            if above == 0 {
+                break;
+            }
+            above -= 1;
+            below += 1;
+        } else {
+            return false
+        }
+    }
+    true
+}
+
+/// Whether the pattern is mirrored between the columns `col_no - 1` and `col_no`. Panics if `col_no` is 0.
+pub fn test_reflection_col(grid: &Grid<char>, col_no: usize) -> bool {
+    let (mut left, mut right) = (col_no - 1, col_no);
+    while right < grid.width() {
+        if grid.column(left).eq(grid.column(right)) {
+            if left == 0 {
+                break;
+            }
+            left -= 1;
+            right += 1;
+        } else {
+            return false
+        }
+    }
+    true
+}
+
+/// The first vertical line of reflection, or else the first horizontal one.
+pub fn find_reflection(grid: &Grid<char>) -> Option<Divider> {
+    (1..grid.width()).find(|i| test_reflection_col(grid, *i)).map(Divider::Col)
+        .or_else(|| (1..grid.height()).find(|i| test_reflection_row(grid, *i)).map(Divider::Row))
+}
+
+/// The number of columns left of the first vertical line of reflection, or else 100 times
+/// the number of rows above the first horizontal one, or 0 without reflection.
+pub fn grid_value(grid: &Grid<char>) -> usize {
+    match find_reflection(grid) {
+        Some(Divider::Col(i)) => i,
+        Some(Divider::Row(i)) => checked_mul(100, i, "the value of a pattern"),
+        None => 0,
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    // One grid per pattern
+    type Input = Vec<Grid<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut patterns = Vec::new();
+        let mut first_line_no = 1;
+        for pattern in input.split("\n\n") {
+            patterns.push(parse_grid(pattern, ".#", first_line_no)?);
+            first_line_no += pattern.lines().count() + 1;
+        }
+        Ok(patterns)
+    }
+
+    fn part1(patterns: &Self::Input) -> impl Display {
+        checked_sum(patterns.iter().map(grid_value), "the sum of the pattern values")
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size))
+    }
+
+    fn pictures(patterns: &Self::Input) -> Vec<Frame> {
+        patterns.iter().enumerate()
+            .map(|(i, pattern)| {
+                let frame = Frame::new(pattern.clone());
+                match find_reflection(pattern) {
+                    Some(divider) => frame
+                        .divide(divider, Color::Red)
+                        .caption(format!("Pattern {}: value {}", i + 1, grid_value(pattern))),
+                    None => frame.caption(format!("Pattern {}: no reflection", i + 1)),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: &[&str]) -> Grid<char> {
+        Grid::from_rows(rows.iter().map(|row| row.chars().collect()).collect())
+    }
+
+    #[test]
+    fn reflection_row() {
+        let pattern = grid(&["#...##..#", "#....#..#", "..##..###", "#####.##.", "#####.##.", "..##..###", "#....#..#"]);
+        assert!(test_reflection_row(&pattern, 4));
+        assert!(!test_reflection_row(&pattern, 1));
+        assert!(!test_reflection_row(&pattern, 3));
+        assert_eq!(grid_value(&pattern), 400);
+    }
+
+    #[test]
+    fn reflection_col() {
+        let pattern = grid(&["#.##..##.", "..#.##.#.", "##......#", "##......#", "..#.##.#.", "..##..##.", "#.#.##.#."]);
+        assert!(test_reflection_col(&pattern, 5));
+        assert!(!test_reflection_col(&pattern, 4));
+        assert_eq!(find_reflection(&pattern), Some(Divider::Col(5)));
+        assert_eq!(grid_value(&pattern), 5);
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_common::Solution;
+use day13::Day13;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day13::parse(EXAMPLE).unwrap();
+    assert_eq!(Day13::part1(&input).to_string(), "405");
+}
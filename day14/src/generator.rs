@@ -0,0 +1,17 @@
+use aoc_common::Rng;
+
+/// A `size` x `size` platform with round and cube-shaped rocks.
+pub(crate) fn input(rng: &mut Rng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            res.push(match rng.range(0..=19) {
+                0..=4 => 'O',
+                5..=7 => '#',
+                _ => '.',
+            });
+        }
+        res.push('\n');
+    }
+    res
+}
@@ -0,0 +1,63 @@
+//! Day 14: Parabolic Reflector Dish, weighing the rounded rocks on a tilted platform.
+
+use std::fmt::Display;
+
+use aoc_common::{checked_add, is_recording, parse_grid, record, Color, Frame, Grid, ParseError, Point, Rng, Solution};
+
+mod generator;
+
+/// The load on the north beams once all rounded rocks ('O') rolled north, where each rock weighs
+/// the number of rows from its row to the south edge.
+pub fn total_load_after_tilting_north(grid: &Grid<char>) -> usize {
+    let dim_y = grid.height();
+
+    // The platform with the rocks rolled so far, only kept for the visualization
+    let mut tilted = is_recording().then(|| grid.clone());
+
+    let mut total_weight = 0;
+    for (col_no, col) in grid.columns().enumerate() {
+        let mut current_weight = dim_y;
+        let mut rolled = Vec::new();
+        for (i, char) in col.enumerate() {
+            match char {
+                'O' => {
+                    if let Some(tilted) = &mut tilted {
+                        tilted[Point::new(col_no, i)] = '.';
+                        tilted[Point::new(col_no, dim_y - current_weight)] = 'O';
+                        rolled.push(Point::new(col_no, dim_y - current_weight));
+                    }
+                    total_weight = checked_add(total_weight, current_weight, "the total load");
+                    current_weight -= 1;
+                },
+                '#' => current_weight = dim_y - i - 1,
+                _ => continue
+            }
+        }
+        if let Some(tilted) = &tilted {
+            record(|| Frame::new(tilted.clone())
+                .mark_all(rolled, Color::Yellow)
+                .caption(format!("Column {col_no}: load {total_weight}")));
+        }
+    }
+    total_weight
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input, "O#.", 1)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        total_load_after_tilting_north(grid)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size))
+    }
+}
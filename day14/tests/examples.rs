@@ -0,0 +1,10 @@
+use aoc_common::Solution;
+use day14::Day14;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day14::parse(EXAMPLE).unwrap();
+    assert_eq!(Day14::part1(&input).to_string(), "136");
+}
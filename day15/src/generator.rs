@@ -0,0 +1,17 @@
+use aoc_common::Rng;
+
+/// `size` steps, using about `size / 3` different labels so that lenses get replaced and removed.
+/// The focal lengths go up to `max_focal_length`, 9 in the puzzle, with values near `u32::MAX`
+/// the focusing power outgrows a `u64` from about 1000000 steps.
+pub(crate) fn input(rng: &mut Rng, size: usize, max_focal_length: u32) -> String {
+    let labels: Vec<String> = (0..size.div_ceil(3))
+        .map(|_| (0..rng.range(2..=6)).map(|_| rng.range(b'a'..=b'z') as char).collect())
+        .collect();
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = rng.choose(&labels);
+            if rng.chance(0.3) { format!("{label}-") } else { format!("{label}={}", rng.range(1..=max_focal_length.max(1))) }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
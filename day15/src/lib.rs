@@ -0,0 +1,180 @@
+//! Day 15: Lens Library, the HASH algorithm and the lens arrangement it drives.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_common::{checked_add, checked_mul, checked_sum, param, parse_number, Explanation, Param, ParseError, Rng, Solution};
+use indexmap::IndexMap;
+
+mod generator;
+
+/// The boxes of lenses, 256 in the puzzle, each holding the focal lengths of its lenses by label in order.
+/// A lens goes into the box of its label's HASH, modulo the number of boxes.
+pub struct LensLibrary<'a> {
+    boxes: HashMap<i32, IndexMap<&'a str, u32>>
+}
+
+impl<'a> LensLibrary<'a> {
+    /// Performs a step like "rn=1", putting in or replacing the lens "rn", or "cm-", removing the lens "cm".
+    /// Panics on other steps.
+    pub fn perform_single_operation(&mut self, op: &'a str) {
+        if op.contains('=') {
+            let mut iter = op.split('=');
+            let label = iter.next().unwrap();
+            let focal_length: u32 = iter.next().unwrap().parse().unwrap();
+            let box_no = self.box_of(label);
+            let relevant_box = self.boxes.get_mut(&box_no).unwrap();
+            *relevant_box.entry(label).or_insert(focal_length) = focal_length;
+        } else {
+            let mut iter = op.split('-');
+            let label = iter.next().unwrap();
+            let box_no = self.box_of(label);
+            let relevant_box = self.boxes.get_mut(&box_no).unwrap();
+            relevant_box.shift_remove(label);
+        }
+
+    }
+
+    /// Sum of the box number plus one, times the slot of the lens, times its focal length over all lenses.
+    pub fn get_focusing_power(self) -> u64 {
+        let mut total = 0u64;
+        for (box_no, lenses) in &self.boxes {
+            for (i, lens) in lenses.values().enumerate() {
+                let power = checked_mul(*box_no as u64 + 1, i as u64 + 1, "the focusing power of a lens");
+                let power = checked_mul(power, *lens as u64, "the focusing power of a lens");
+                total = checked_add(total, power, "the total focusing power");
+            }
+        }
+        total
+    }
+
+    /// A library with 256 empty boxes.
+    pub fn new() -> Self {
+        Self::with_boxes(256)
+    }
+
+    /// A library with `n_boxes` empty boxes, panics if it is 0.
+    pub fn with_boxes(n_boxes: i32) -> Self {
+        assert!(n_boxes > 0, "A lens library needs boxes!");
+        let mut boxes: HashMap<i32, IndexMap<&str, u32>>  = HashMap::new();
+        for i in 0..n_boxes {
+            boxes.insert(i, IndexMap::new());
+        }
+    Self { boxes }
+    }
+
+    /// The box the lens `label` goes into.
+    pub fn box_of(&self, label: &str) -> i32 {
+        compute_hash(label) % self.boxes.len() as i32
+    }
+
+    /// The labels and focal lengths of the lenses in box `box_no`, front to back.
+    pub fn lenses(&self, box_no: i32) -> impl Iterator<Item = (&'a str, u32)> + '_ {
+        self.boxes.get(&box_no).into_iter().flat_map(|lenses| lenses.iter().map(|(label, lens)| (*label, *lens)))
+    }
+}
+
+impl Default for LensLibrary<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The HASH algorithm: a value from 0 to 255 for `string`.
+pub fn compute_hash(string: &str) -> i32 {
+    let mut current = 0;
+    for char in string.chars() {
+        current += char as i32;
+        current *= 17;
+        current %= 256;
+    }
+    current
+}
+
+fn check_step(line: &str, step: &str) -> Result<(), ParseError> {
+    let error = || ParseError::new("Expected \"<label>=<focal length>\" or \"<label>-\"").at(line, step);
+    if let Some((label, focal_length)) = step.split_once('=') {
+        if label.is_empty() {
+            return Err(error());
+        }
+        parse_number::<u32>(line, focal_length)?;
+    } else if step.strip_suffix('-').is_none_or(str::is_empty) {
+        return Err(error());
+    }
+    Ok(())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    const PARAMS: &'static [Param] = &[
+        Param::int("boxes", 1, i32::MAX as i128),
+        Param::int("gen_max_focal_length", 1, u32::MAX as i128),
+    ];
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input.lines().next().ok_or(ParseError::new("Empty input"))?;
+        for step in line.split(',') {
+            check_step(line, step).map_err(|e| e.on_line(1, line))?;
+        }
+        Ok(line.split(',').map(String::from).collect())
+    }
+
+    fn part1(steps: &Self::Input) -> impl Display {
+        checked_sum(steps.iter().map(|string| compute_hash(string) as u64), "the sum of the HASH values")
+    }
+
+    fn part2(steps: &Self::Input) -> Option<impl Display> {
+        let mut lens_library = LensLibrary::with_boxes(param(Self::DAY, "boxes", 256));
+        for operation in steps {
+            lens_library.perform_single_operation(operation);
+        }
+        Some(lens_library.get_focusing_power())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size, param(Self::DAY, "gen_max_focal_length", 9)))
+    }
+
+    fn explain(steps: &Self::Input) -> Vec<Explanation> {
+        let mut lens_library = LensLibrary::with_boxes(param(Self::DAY, "boxes", 256));
+        steps.iter().enumerate()
+            .map(|(i, step)| {
+                lens_library.perform_single_operation(step);
+                let label = step.split(['=', '-']).next().unwrap();
+                let box_no = lens_library.box_of(label);
+                let lenses: Vec<String> = lens_library.lenses(box_no).map(|(label, lens)| format!("[{label} {lens}]")).collect();
+                let contents = if lenses.is_empty() { String::from("is empty") } else { format!("holds {}", lenses.join(" ")) };
+                Explanation::new(1, format!("Step {} \"{step}\": HASH {}", i + 1, compute_hash(step)))
+                    .detail(format!("box {box_no} of label {label} {contents}"))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash() {
+        assert_eq!(compute_hash("HASH"), 52);
+        assert_eq!(compute_hash("rn=1"), 30);
+        assert_eq!(compute_hash("rn"), 0);
+        assert_eq!(compute_hash("qp"), 1);
+    }
+
+    #[test]
+    fn focusing_power() {
+        let mut library = LensLibrary::new();
+        for step in ["rn=1", "cm-", "qp=3", "cm=2", "qp-", "pc=4", "ot=9", "ab=5", "pc-", "pc=6", "ot=7"] {
+            library.perform_single_operation(step);
+        }
+        assert_eq!(library.lenses(3).collect::<Vec<_>>(), [("ot", 7), ("ab", 5), ("pc", 6)]);
+        assert_eq!(library.get_focusing_power(), 145);
+    }
+}
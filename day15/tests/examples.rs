@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use day15::Day15;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day15::parse(EXAMPLE).unwrap();
+    assert_eq!(Day15::part1(&input).to_string(), "1320");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day15::parse(EXAMPLE).unwrap();
+    assert_eq!(Day15::part2(&input).unwrap().to_string(), "145");
+}
+
+#[test]
+fn focal_lengths_beyond_i32() {
+    let input = Day15::parse("rn=4294967295,cm=4294967295\n").unwrap();
+    assert_eq!(Day15::part2(&input).unwrap().to_string(), "12884901885");
+}
@@ -0,0 +1,13 @@
+use aoc_common::Rng;
+
+/// A `size` x `size` contraption, mostly empty space with some mirrors and splitters.
+pub(crate) fn input(rng: &mut Rng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            res.push(if rng.chance(0.85) { '.' } else { *rng.choose(&['/', '\\', '|', '-']) });
+        }
+        res.push('\n');
+    }
+    res
+}
@@ -0,0 +1,242 @@
+//! Day 16: The Floor Will Be Lava, tracing beams of light through mirrors and splitters.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_common::{param, parse_grid, record, Color, Direction, Frame, Grid, Param, ParseError, Point, Rng, Solution};
+use indicatif::ProgressBar;
+
+mod generator;
+
+/// A beam of light, with all points it passed. It dies when leaving the grid or
+/// when following a path another ray took already.
+#[derive(Debug)]
+pub struct Ray<T> {
+    pub points: Vec<Point<T>>,
+    pub is_alive: bool
+}
+
+
+impl<'a, T> Ray<T> {
+    pub fn get_current(&'a self) -> Option<&'a Point<T>> {
+        self.points.last()
+    }
+
+    pub fn get_previous(&'a self) -> Option<&'a Point<T>> {
+        self.points.get(self.points.len() - 2)
+    }
+
+    /// A ray at `current`, coming from the neighbouring point `previous`.
+    pub fn new_starting_from(previous: Point<T>, current: Point<T>) -> Self {
+        Self {points: vec![previous, current], is_alive: true}
+    }
+
+}
+
+impl<T: PartialEq> Ray<T> {
+    /// Whether the ray went from `from` to `to` in one step.
+    pub fn contains_step(&self, from: &Point<T>, to: &Point<T>) -> bool {
+        for (i, point) in self.points.iter().enumerate() {
+            if *point == *from && i < self.points.len() - 1 && self.points[i+1] == *to {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl <'a> Ray<i32> {
+    /// The points inside the grid the ray continues to from the tile it is on,
+    /// two if the tile splits it.
+    pub fn determine_next_positions(&'a self, grid: &Grid<char>) -> Vec<Point<i32>> {
+        let current_pos = self.get_current().unwrap();
+        let previous_pos = self.get_previous().unwrap();
+        let direction = Direction::of_vector(*current_pos - *previous_pos).expect("Rays move one step at a time!");
+        let current_space = grid[current_pos.try_cast::<usize>().unwrap()];
+        let directions = match current_space {
+            '.' => vec![direction],
+            '/' => vec![direction.reflect_slash()],
+            '\\' => vec![direction.reflect_backslash()],
+            '-' if direction.is_vertical() => vec![Direction::W, Direction::E],
+            '|' if direction.is_horizontal() => vec![Direction::N, Direction::S],
+            '-' | '|' => vec![direction],
+            _ => panic!("Unknown symbol detected!"),
+        };
+        directions.into_iter()
+            .filter_map(|direction| current_pos.checked_add(direction.vector()))
+            .filter(|p| grid.pos(*p).is_some())
+            .collect()
+    }
+
+    /// Moves the ray one step further, returning the new ray if it was split.
+    pub fn move_one_step(&mut self, grid: &Grid<char>) -> Option<Ray<i32>> {
+        let mut next_positions = self.determine_next_positions(grid);
+
+        match next_positions.len() {
+            0 => {
+                self.is_alive = false;
+                None
+            },
+            1 => {
+                self.points.push(next_positions.remove(0));
+                None
+            },
+            2 => {
+                self.points.push(next_positions.remove(0));
+                Some(Ray::new_starting_from(*self.get_previous().unwrap(), next_positions.remove(0)))
+            },
+            _ => panic!("Determine next position returned more positions than expected!")
+        }
+
+
+    }
+}
+
+/// How often the light passes each tile it reaches, starting with `ray` outside of the grid,
+/// including that start. Stops after the `max_steps` parameter, 20000 by default, if rays are still moving.
+pub fn beams_per_tile(ray: Ray<i32>, grid: &Grid<char>) -> HashMap<Point<i32>, u32> {
+    let mut rays = vec![ray];
+    for step in 0..param(Day16::DAY, "max_steps", 20000) {  // Hack :P
+        let mut any_alive = false;
+        let mut new_rays = Vec::new();
+        for ray in rays.iter_mut() {
+            if ray.is_alive {
+                any_alive = true;
+                if let Some(new_ray) = ray.move_one_step(grid) {
+                    new_rays.push(new_ray);
+                }
+            }
+
+        }
+        rays.append(&mut new_rays);
+
+        let mut to_die = Vec::new();
+
+        for (i, ray) in rays.iter().enumerate() {
+            if ray.is_alive {
+                for (j, other) in rays.iter().enumerate() {
+                    if i != j && other.contains_step(ray.get_previous().unwrap(), ray.get_current().unwrap()) {
+                        if ray.get_previous().unwrap() == other.get_previous().unwrap() && ray.get_current().unwrap() == other.get_current().unwrap() && i > j {
+                            continue;
+                        }
+                        to_die.push(i);
+                    }
+                }
+            }
+        }
+
+        for i in to_die {
+            rays.get_mut(i).unwrap().is_alive = false;
+        }
+        record(|| {
+            let in_grid = |point: &Point<i32>| grid.pos(*point);
+            Frame::new(grid.clone())
+                .mark_all(rays.iter().flat_map(|ray| ray.points.iter()).filter_map(in_grid), Color::Yellow)
+                .mark_all(rays.iter().filter(|ray| ray.is_alive).filter_map(Ray::get_current).filter_map(in_grid), Color::Red)
+                .caption(format!("Step {step}: {} rays", rays.len()))
+        });
+        log::trace!(
+            "Step {step}: {} rays, alive at {:?}",
+            rays.len(),
+            rays.iter().filter(|ray| ray.is_alive).filter_map(Ray::get_current).collect::<Vec<_>>()
+        );
+
+        if !any_alive {
+            break;
+        }
+    }
+    let mut visited_points = HashMap::new();
+    for ray in rays.iter() {
+        for point in ray.points.iter() {
+            *visited_points.entry(*point).or_insert(0) += 1;
+        }
+    }
+    log::debug!("The ray entering at {:?} energizes {} tiles", rays[0].points[1], visited_points.len() - 1);
+    visited_points
+}
+
+/// The number of tiles the light passes, starting with `ray` outside of the grid, see [`beams_per_tile`].
+pub fn number_of_energized_tiles_for_starting_ray(ray: Ray<i32>, grid: &Grid<char>) -> u32 {
+    beams_per_tile(ray, grid).len() as u32 - 1
+}
+
+/// The most tiles energized by a ray entering from any edge tile, see [`number_of_energized_tiles_for_starting_ray`].
+pub fn max_energized_tiles(grid: &Grid<char>) -> u32 {
+    let mut energized = Vec::new();
+
+    // Only drawn when asked for diagnostics, so that runs stay silent by default
+    let n_rays = 2 * (grid.height() + grid.width()) as u64;
+    let bar = if log::log_enabled!(log::Level::Info) { ProgressBar::new(n_rays) } else { ProgressBar::hidden() };
+    for j in 0..grid.height() {
+        let ray = Ray::new_starting_from(
+            Point {x:-1, y:j as i32}, Point {x: 0, y: j as i32}
+        );
+        energized.push(number_of_energized_tiles_for_starting_ray(ray, grid));
+        bar.inc(1);
+        let ray = Ray::new_starting_from(
+            Point {x:grid.width() as i32, y:j as i32}, Point {x: grid.width() as i32 - 1, y: j as i32}
+        );
+        energized.push(number_of_energized_tiles_for_starting_ray(ray, grid));
+        bar.inc(1);
+    }
+
+    for i in 0..grid.width() {
+        let ray = Ray::new_starting_from(
+            Point {x:i as i32, y:-1}, Point {x: i as i32, y: 0}
+        );
+        energized.push(number_of_energized_tiles_for_starting_ray(ray, grid));
+        bar.inc(1);
+        let ray = Ray::new_starting_from(
+            Point {x:i as i32, y:grid.height() as i32}, Point {x:i as i32, y: grid.height() as i32 -1}
+        );
+        energized.push(number_of_energized_tiles_for_starting_ray(ray, grid));
+        bar.inc(1);
+
+    }
+    bar.finish_and_clear();
+    *energized.iter().max().unwrap()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    const PARAMS: &'static [Param] = &[Param::int("max_steps", 0, i32::MAX as i128)];
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input, ".|-/\\", 1)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        let ray = Ray::new_starting_from(Point {x: -1, y: 0}, Point {x: 0, y: 0});
+        number_of_energized_tiles_for_starting_ray(ray, grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Option<impl Display> {
+        Some(max_energized_tiles(grid))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::input(rng, size))
+    }
+
+    fn pictures(grid: &Self::Input) -> Vec<Frame> {
+        let ray = Ray::new_starting_from(Point {x: -1, y: 0}, Point {x: 0, y: 0});
+        let beams: Vec<(Point<usize>, u32)> = beams_per_tile(ray, grid).into_iter()
+            .filter_map(|(point, n)| grid.pos(point).map(|pos| (pos, n)))
+            .collect();
+        let most = beams.iter().map(|(_, n)| *n).max().unwrap_or(1);
+
+        let mut frame = Frame::new(grid.clone());
+        for (pos, n) in &beams {
+            frame = frame.shade(*pos, Color::Red, 0.2 + 0.8 * *n as f64 / most as f64);
+        }
+        let frame = frame
+            .caption(format!("{} energized tiles, passed by up to {most} beams", beams.len()))
+            .legend(Color::Red, "energized, darker where more beams pass");
+        vec![frame]
+    }
+}
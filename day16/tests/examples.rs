@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day16::Day16;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    let input = Day16::parse(EXAMPLE).unwrap();
+    assert_eq!(Day16::part1(&input).to_string(), "46");
+}
+
+#[test]
+fn part2_example() {
+    let input = Day16::parse(EXAMPLE).unwrap();
+    assert_eq!(Day16::part2(&input).unwrap().to_string(), "51");
+}